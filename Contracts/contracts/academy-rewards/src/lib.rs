@@ -1,9 +1,14 @@
 #![no_std]
 
+use shared::admin_transfer::{AdminTransfer, AdminTransferError, PendingAdmin};
+use shared::events::{ContractUpgradedEvent, EventEmitter};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Env, String, Symbol,
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String, Symbol,
 };
 
+/// Version of this contract implementation
+const CONTRACT_VERSION: u32 = 1;
+
 // Contract Errors
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -22,6 +27,19 @@ pub enum ContractError {
     BadgeExpired = 11,
     RedemptionLimitReached = 12,
     TransactionAlreadyRedeemed = 13,
+    NoPendingAdmin = 14,
+    NotPendingAdmin = 15,
+    AdminTransferExpired = 16,
+}
+
+impl From<AdminTransferError> for ContractError {
+    fn from(error: AdminTransferError) -> Self {
+        match error {
+            AdminTransferError::NoPendingAdmin => ContractError::NoPendingAdmin,
+            AdminTransferError::NotPendingAdmin => ContractError::NotPendingAdmin,
+            AdminTransferError::TransferExpired => ContractError::AdminTransferExpired,
+        }
+    }
 }
 
 // Storage keys
@@ -35,6 +53,7 @@ pub enum DataKey {
     TotalBadgesMinted(u32),          // Counter per badge type
     PausedState,
     UsedTransactionHash(String), // Track used transaction hashes globally
+    Version,                     // Version of the installed code
 }
 
 // Badge struct
@@ -87,10 +106,52 @@ impl AcademyRewardsContract {
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::PausedState, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &CONTRACT_VERSION);
 
         Ok(())
     }
 
+    /// Install new WASM and record its version
+    ///
+    /// Authorized by the stored admin, normally the governance contract.
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &new_version);
+
+        EventEmitter::contract_upgraded(
+            &env,
+            ContractUpgradedEvent {
+                upgraded_by: admin,
+                new_wasm_hash,
+                new_version,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get current contract version
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
     // ========== ADMIN FUNCTIONS ==========
 
     /// Create a new badge type
@@ -228,6 +289,39 @@ impl AcademyRewardsContract {
         Ok(())
     }
 
+    /// Propose a new admin; it takes over once it accepts
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        AdminTransfer::propose(&env, admin, new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        new_admin.require_auth();
+
+        let previous_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+
+        AdminTransfer::accept(&env, previous_admin, &new_admin)?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env, &admin)?;
+        AdminTransfer::cancel(&env, admin)?;
+        Ok(())
+    }
+
     // ========== USER FUNCTIONS ==========
 
     /// Redeem badge for fee discount
@@ -350,6 +444,11 @@ impl AcademyRewardsContract {
             .get(&DataKey::RedemptionHistory(user, index))
     }
 
+    /// Get the pending admin transfer, if any
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        AdminTransfer::pending(&env)
+    }
+
     // ========== HELPER FUNCTIONS ==========
 
     fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
@@ -3,6 +3,7 @@ mod tests {
     use crate::{
         AcademyRewardsContract, AcademyRewardsContractClient, BadgeMetadata, ContractError, DataKey,
     };
+    use shared::admin_transfer::ADMIN_TRANSFER_WINDOW;
     use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
 
     fn setup_env() -> (Env, Address, Address, Address) {
@@ -210,6 +211,59 @@ mod tests {
         assert_eq!(pause_err, Err(Ok(ContractError::Unauthorized)));
     }
 
+    #[test]
+    fn test_two_step_admin_transfer() {
+        let (env, admin, _user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let new_admin = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        let not_admin = client.try_propose_admin(&outsider, &new_admin);
+        assert_eq!(not_admin, Err(Ok(ContractError::Unauthorized)));
+
+        client.propose_admin(&admin, &new_admin);
+        let pending = client.get_pending_admin().unwrap();
+        assert_eq!(pending.admin, new_admin);
+        assert_eq!(pending.expires_at, 1000 + ADMIN_TRANSFER_WINDOW);
+
+        // The old admin stays in charge until the transfer is accepted
+        client.set_paused(&admin, &false);
+
+        let wrong_acceptor = client.try_accept_admin(&outsider);
+        assert_eq!(wrong_acceptor, Err(Ok(ContractError::NotPendingAdmin)));
+
+        client.accept_admin(&new_admin);
+        assert_eq!(client.get_pending_admin(), None);
+
+        let old_admin = client.try_set_paused(&admin, &true);
+        assert_eq!(old_admin, Err(Ok(ContractError::Unauthorized)));
+        client.set_paused(&new_admin, &true);
+    }
+
+    #[test]
+    fn test_admin_transfer_expiry_and_cancel() {
+        let (env, admin, _user, contract_id) = setup_env();
+        let client = AcademyRewardsContractClient::new(&env, &contract_id);
+        let new_admin = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        let nothing_pending = client.try_accept_admin(&new_admin);
+        assert_eq!(nothing_pending, Err(Ok(ContractError::NoPendingAdmin)));
+
+        client.propose_admin(&admin, &new_admin);
+        set_timestamp(&env, 1000 + ADMIN_TRANSFER_WINDOW + 1);
+        let expired = client.try_accept_admin(&new_admin);
+        assert_eq!(expired, Err(Ok(ContractError::AdminTransferExpired)));
+
+        client.cancel_admin_transfer(&admin);
+        assert_eq!(client.get_pending_admin(), None);
+        let cancelled = client.try_cancel_admin_transfer(&admin);
+        assert_eq!(cancelled, Err(Ok(ContractError::NoPendingAdmin)));
+    }
+
     fn set_timestamp(env: &Env, timestamp: u64) {
         let mut ledger_info = env.ledger().get();
         ledger_info.timestamp = timestamp;
@@ -1,4 +1,11 @@
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+use shared::admin_transfer::{AdminTransfer, AdminTransferError, PendingAdmin};
+use shared::events::{ContractUpgradedEvent, EventEmitter};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map,
+};
+
+/// Version of this contract implementation
+const CONTRACT_VERSION: u32 = 1;
 
 /// Vesting schedule for an academy reward
 #[contracttype]
@@ -49,6 +56,7 @@ pub struct RevokeEvent {
 }
 
 /// Vesting error codes
+#[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum VestingError {
@@ -61,23 +69,18 @@ pub enum VestingError {
     Revoked = 4007,
     InvalidTimelock = 4008,
     NotEnoughTimeForRevoke = 4009,
+    NoPendingAdmin = 4010,
+    NotPendingAdmin = 4011,
+    AdminTransferExpired = 4012,
 }
 
-impl From<VestingError> for soroban_sdk::Error {
-    fn from(error: VestingError) -> Self {
-        soroban_sdk::Error::from_contract_error(error as u32)
-    }
-}
-
-impl From<&VestingError> for soroban_sdk::Error {
-    fn from(error: &VestingError) -> Self {
-        soroban_sdk::Error::from_contract_error(*error as u32)
-    }
-}
-
-impl From<soroban_sdk::Error> for VestingError {
-    fn from(_error: soroban_sdk::Error) -> Self {
-        VestingError::Unauthorized
+impl From<AdminTransferError> for VestingError {
+    fn from(error: AdminTransferError) -> Self {
+        match error {
+            AdminTransferError::NoPendingAdmin => VestingError::NoPendingAdmin,
+            AdminTransferError::NotPendingAdmin => VestingError::NotPendingAdmin,
+            AdminTransferError::TransferExpired => VestingError::AdminTransferExpired,
+        }
     }
 }
 
@@ -118,9 +121,113 @@ impl AcademyVestingContract {
         let counter_key = symbol_short!("cnt");
         env.storage().persistent().set(&counter_key, &0u64);
 
+        // Store contract version
+        let version_key = symbol_short!("ver");
+        env.storage()
+            .persistent()
+            .set(&version_key, &CONTRACT_VERSION);
+
         Ok(())
     }
 
+    /// Install new WASM and record its version (admin only)
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), VestingError> {
+        let admin_key = symbol_short!("admin");
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(VestingError::Unauthorized)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        let version_key = symbol_short!("ver");
+        env.storage().persistent().set(&version_key, &new_version);
+
+        EventEmitter::contract_upgraded(
+            &env,
+            ContractUpgradedEvent {
+                upgraded_by: admin,
+                new_wasm_hash,
+                new_version,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get current contract version
+    pub fn get_version(env: Env) -> u32 {
+        let version_key = symbol_short!("ver");
+        env.storage().persistent().get(&version_key).unwrap_or(0)
+    }
+
+    /// Propose a new admin (admin only); it takes over once it accepts
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), VestingError> {
+        admin.require_auth();
+
+        let admin_key = symbol_short!("admin");
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        if admin != stored_admin {
+            return Err(VestingError::Unauthorized);
+        }
+
+        AdminTransfer::propose(&env, admin, new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), VestingError> {
+        new_admin.require_auth();
+
+        let admin_key = symbol_short!("admin");
+        let previous_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        AdminTransfer::accept(&env, previous_admin, &new_admin)?;
+        env.storage().persistent().set(&admin_key, &new_admin);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer (admin only)
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), VestingError> {
+        admin.require_auth();
+
+        let admin_key = symbol_short!("admin");
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(VestingError::Unauthorized)?;
+
+        if admin != stored_admin {
+            return Err(VestingError::Unauthorized);
+        }
+
+        AdminTransfer::cancel(&env, admin)?;
+        Ok(())
+    }
+
+    /// Get the pending admin transfer, if any
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        AdminTransfer::pending(&env)
+    }
+
     /// Grant a vesting schedule to a beneficiary
     pub fn grant_vesting(
         env: Env,
@@ -174,19 +281,10 @@ impl AcademyVestingContract {
             revoke_time: 0,
         };
 
-        // Store schedule
-        let schedules_key = symbol_short!("sched");
-        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = env
-            .storage()
-            .persistent()
-            .get(&schedules_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(&env));
-
-        schedules.set(next_id, schedule);
-        env.storage().persistent().set(&schedules_key, &schedules);
-
-        // Update counter
-        env.storage().persistent().set(&counter_key, &next_id);
+        // Store schedule under its own key
+        let schedule_key = symbol_short!("sched_");
+        let individual_key = (schedule_key, next_id);
+        env.storage().persistent().set(&individual_key, &schedule);
 
         // Emit grant event
         let grant_event = GrantEvent {
@@ -212,13 +310,7 @@ impl AcademyVestingContract {
         // Optimized: Direct individual storage access instead of loading entire map
         let schedule_key = symbol_short!("sched_");
         let individual_key = (schedule_key, grant_id);
-        let mut schedule: VestingSchedule = env
-            .storage()
-            .persistent()
-            .get(&schedules_key)
-            .ok_or(VestingError::GrantNotFound)?;
-
-        let mut schedule = schedules.get(grant_id).ok_or(VestingError::GrantNotFound)?;
+        let mut schedule = Self::load_schedule(&env, grant_id)?;
 
         // Verify beneficiary matches
         if schedule.beneficiary != beneficiary {
@@ -306,13 +398,7 @@ impl AcademyVestingContract {
         // Optimized: Direct individual storage access
         let schedule_key = symbol_short!("sched_");
         let individual_key = (schedule_key, grant_id);
-        let mut schedule: VestingSchedule = env
-            .storage()
-            .persistent()
-            .get(&schedules_key)
-            .ok_or(VestingError::GrantNotFound)?;
-
-        let mut schedule = schedules.get(grant_id).ok_or(VestingError::GrantNotFound)?;
+        let mut schedule = Self::load_schedule(&env, grant_id)?;
 
         // Cannot revoke already claimed
         if schedule.claimed {
@@ -356,34 +442,33 @@ impl AcademyVestingContract {
 
     /// Query vesting schedule details
     pub fn get_vesting(env: Env, grant_id: u64) -> Result<VestingSchedule, VestingError> {
-        // Optimized: Direct individual storage access
-        let schedule_key = symbol_short!("sched_");
-        let individual_key = (schedule_key, grant_id);
-        env.storage()
-            .persistent()
-            .get(&schedules_key)
-            .ok_or(VestingError::GrantNotFound)?;
-
-        schedules.get(grant_id).ok_or(VestingError::GrantNotFound)
+        Self::load_schedule(&env, grant_id)
     }
 
     /// Calculate vested amount at current time
     pub fn get_vested_amount(env: Env, grant_id: u64) -> Result<i128, VestingError> {
-        // Optimized: Direct individual storage access
-        let schedule_key = symbol_short!("sched_");
-        let individual_key = (schedule_key, grant_id);
-        let schedule: VestingSchedule = env
-            .storage()
-            .persistent()
-            .get(&schedules_key)
-            .ok_or(VestingError::GrantNotFound)?;
-
-        let schedule = schedules.get(grant_id).ok_or(VestingError::GrantNotFound)?;
+        let schedule = Self::load_schedule(&env, grant_id)?;
 
         let current_time = env.ledger().timestamp();
         Self::calculate_vested_amount(&schedule, current_time)
     }
 
+    /// Internal helper: load a grant's schedule from its own key. Grants made
+    /// while schedules were kept in a single `sched` map are read from that
+    /// map instead, until a claim or revoke rewrites them under their own key.
+    fn load_schedule(env: &Env, grant_id: u64) -> Result<VestingSchedule, VestingError> {
+        let individual_key = (symbol_short!("sched_"), grant_id);
+        if let Some(schedule) = env.storage().persistent().get(&individual_key) {
+            return Ok(schedule);
+        }
+
+        let legacy: Option<Map<u64, VestingSchedule>> =
+            env.storage().persistent().get(&symbol_short!("sched"));
+        legacy
+            .and_then(|schedules| schedules.get(grant_id))
+            .ok_or(VestingError::GrantNotFound)
+    }
+
     /// Internal helper: calculate vested amount based on schedule and current time
     fn calculate_vested_amount(
         schedule: &VestingSchedule,
@@ -1,4 +1,5 @@
 use super::*;
+use shared::admin_transfer::ADMIN_TRANSFER_WINDOW;
 use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Address, Env};
 
 fn setup_env() -> (Env, Address, Address, Address, Address) {
@@ -157,6 +158,55 @@ fn test_claim_success_transfers_tokens() {
     assert!(schedule.claimed);
 }
 
+#[test]
+fn test_legacy_schedule_map_is_still_readable() {
+    let (env, admin, beneficiary, governance, contract_id) = setup_env();
+    let (token_id, token_client, token_admin) = setup_token(&env);
+    let client = AcademyVestingContractClient::new(&env, &contract_id);
+
+    client.init(&admin, &token_id, &governance);
+
+    // A grant stored the way schedules were kept before per-grant keys
+    env.as_contract(&contract_id, || {
+        let mut schedules: soroban_sdk::Map<u64, VestingSchedule> = soroban_sdk::Map::new(&env);
+        schedules.set(
+            1,
+            VestingSchedule {
+                beneficiary: beneficiary.clone(),
+                amount: 500,
+                start_time: 0,
+                cliff: 0,
+                duration: 100,
+                claimed: false,
+                revoked: false,
+                revoke_time: 0,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&soroban_sdk::symbol_short!("sched"), &schedules);
+        env.storage()
+            .persistent()
+            .set(&soroban_sdk::symbol_short!("cnt"), &1u64);
+    });
+
+    set_timestamp(&env, 50);
+    assert_eq!(client.get_vesting(&1).amount, 500);
+    assert_eq!(client.get_vested_amount(&1), 250);
+
+    token_admin.mint(&contract_id, &500);
+    set_timestamp(&env, 200);
+    assert_eq!(client.claim(&1, &beneficiary), 500);
+    assert_eq!(token_client.balance(&beneficiary), 500);
+
+    // The claim moved the grant to its own key
+    assert!(client.get_vesting(&1).claimed);
+    assert_eq!(
+        client.try_claim(&1, &beneficiary),
+        Err(Ok(VestingError::AlreadyClaimed))
+    );
+}
+
 #[test]
 fn test_claim_insufficient_balance() {
     let (env, admin, beneficiary, governance, contract_id) = setup_env();
@@ -317,6 +367,60 @@ fn test_get_vesting_and_vested_amount_errors() {
     assert!(matches!(missing_amount, Err(VestingError::GrantNotFound)));
 }
 
+#[test]
+fn test_two_step_admin_transfer() {
+    let (env, admin, _beneficiary, governance, contract_id) = setup_env();
+    let (token_id, _token_client, _token_admin) = setup_token(&env);
+    let client = AcademyVestingContractClient::new(&env, &contract_id);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    client.init(&admin, &token_id, &governance);
+
+    let result = client.try_propose_admin(&outsider, &new_admin);
+    assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
+
+    client.propose_admin(&admin, &new_admin);
+    let pending = client.get_pending_admin().unwrap();
+    assert_eq!(pending.admin, new_admin);
+    assert_eq!(pending.expires_at, 1000 + ADMIN_TRANSFER_WINDOW);
+
+    // The old admin stays in charge until the transfer is accepted
+    let (stored_admin, _, _) = client.get_info();
+    assert_eq!(stored_admin, admin);
+
+    let result = client.try_accept_admin(&outsider);
+    assert_eq!(result, Err(Ok(VestingError::NotPendingAdmin)));
+
+    client.accept_admin(&new_admin);
+    let (stored_admin, _, _) = client.get_info();
+    assert_eq!(stored_admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_admin_transfer_expiry_and_cancel() {
+    let (env, admin, _beneficiary, governance, contract_id) = setup_env();
+    let (token_id, _token_client, _token_admin) = setup_token(&env);
+    let client = AcademyVestingContractClient::new(&env, &contract_id);
+    let new_admin = Address::generate(&env);
+
+    client.init(&admin, &token_id, &governance);
+
+    let result = client.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(VestingError::NoPendingAdmin)));
+
+    client.propose_admin(&admin, &new_admin);
+    set_timestamp(&env, 1000 + ADMIN_TRANSFER_WINDOW + 1);
+    let result = client.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(VestingError::AdminTransferExpired)));
+
+    client.cancel_admin_transfer(&admin);
+    assert_eq!(client.get_pending_admin(), None);
+    let result = client.try_cancel_admin_transfer(&admin);
+    assert_eq!(result, Err(Ok(VestingError::NoPendingAdmin)));
+}
+
 fn set_timestamp(env: &Env, timestamp: u64) {
     let mut ledger_info = env.ledger().get();
     ledger_info.timestamp = timestamp;
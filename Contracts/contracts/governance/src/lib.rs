@@ -0,0 +1,422 @@
+#![no_std]
+#![allow(clippy::too_many_arguments)]
+
+use shared::governance::{
+    ApprovalSignature, ContractCall, GovernanceError, GovernanceManager, GovernanceRole,
+    ProposalMetadata, ProposalPage, ProposalParams, ProposalStatus, Rationale, UpgradeProposal,
+    VoteChoice, VoteTally, VERSION_KEY,
+};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol, Val, Vec};
+
+/// Version of this contract implementation
+const CONTRACT_VERSION: u32 = 1;
+
+/// Standalone governance contract for the Stellara suite
+///
+/// One role set and proposal history govern every Stellara contract. Each
+/// governed contract is deployed (or re-pointed) with this contract as its
+/// admin and exposes `upgrade(new_wasm_hash, new_version)` and `get_version`,
+/// so upgrades and admin calls only happen through approved proposals.
+#[contract]
+pub struct GovernanceContract;
+
+#[contractimpl]
+impl GovernanceContract {
+    /// Initialize with the first admin, approvers and executor
+    pub fn init(
+        env: Env,
+        admin: Address,
+        approvers: Vec<Address>,
+        executor: Address,
+    ) -> Result<(), GovernanceError> {
+        // Check if already initialized
+        let init_key = symbol_short!("init");
+        if env.storage().persistent().has(&init_key) {
+            return Err(GovernanceError::Unauthorized);
+        }
+
+        // Set initialization flag
+        env.storage().persistent().set(&init_key, &true);
+
+        // Store roles
+        let roles_key = symbol_short!("roles");
+        let mut roles = soroban_sdk::Map::new(&env);
+        roles.set(admin, GovernanceRole::Admin);
+        for approver in approvers.iter() {
+            roles.set(approver, GovernanceRole::Approver);
+        }
+        roles.set(executor, GovernanceRole::Executor);
+        env.storage().persistent().set(&roles_key, &roles);
+
+        // Store contract version
+        env.storage()
+            .persistent()
+            .set(&VERSION_KEY, &CONTRACT_VERSION);
+
+        Ok(())
+    }
+
+    /// Get current contract version
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().persistent().get(&VERSION_KEY).unwrap_or(0)
+    }
+
+    /// Propose installing new WASM on `target_contract` at its next version
+    ///
+    /// `target_contract` may be this contract itself.
+    pub fn propose_upgrade(
+        env: Env,
+        admin: Address,
+        target_contract: Address,
+        new_contract_hash: BytesN<32>,
+        metadata: ProposalMetadata,
+        approvers: Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        let current_version = GovernanceManager::current_version(&env, &target_contract)?;
+        Self::propose_upgrade_with_params(
+            env,
+            admin,
+            target_contract,
+            new_contract_hash,
+            metadata,
+            approvers,
+            approval_threshold,
+            timelock_delay,
+            ProposalParams::new(current_version + 1),
+        )
+    }
+
+    /// Propose an upgrade with explicit version, cooling-off and breaking-change metadata
+    pub fn propose_upgrade_with_params(
+        env: Env,
+        admin: Address,
+        target_contract: Address,
+        new_contract_hash: BytesN<32>,
+        metadata: ProposalMetadata,
+        approvers: Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+        params: ProposalParams,
+    ) -> Result<u64, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::propose_upgrade(
+            &env,
+            admin,
+            new_contract_hash,
+            target_contract,
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+            params,
+        )
+    }
+
+    /// Propose a governance action calling `function` on `target` with `args`
+    pub fn propose_action(
+        env: Env,
+        admin: Address,
+        target: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        metadata: ProposalMetadata,
+        approvers: Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::propose_action(
+            &env,
+            admin,
+            ContractCall {
+                target,
+                function,
+                args,
+            },
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+        )
+    }
+
+    /// Propose a batch of governance actions that execute atomically, in order
+    pub fn propose_batch(
+        env: Env,
+        admin: Address,
+        calls: Vec<ContractCall>,
+        metadata: ProposalMetadata,
+        approvers: Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::propose_batch(
+            &env,
+            admin,
+            calls,
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+        )
+    }
+
+    /// Approve a proposal
+    pub fn approve_proposal(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::approve_proposal(&env, proposal_id, approver, rationale)
+    }
+
+    /// Register the ed25519 key an approver signs off-chain approvals with
+    pub fn set_signing_key(
+        env: Env,
+        approver: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::set_signing_key(&env, approver, public_key)
+    }
+
+    /// Digest an approver signs to approve `proposal_id`
+    pub fn approval_digest(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> BytesN<32> {
+        GovernanceManager::approval_digest(&env, proposal_id, &approver, &rationale)
+    }
+
+    /// Nonce covered by an approver's next signed approval
+    pub fn get_signature_nonce(env: Env, approver: Address) -> u64 {
+        GovernanceManager::get_signature_nonce(&env, &approver)
+    }
+
+    /// Submit approvals signed off-chain; anyone may relay them
+    pub fn approve_with_signatures(
+        env: Env,
+        proposal_id: u64,
+        signatures: Vec<ApprovalSignature>,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::approve_with_signatures(&env, proposal_id, signatures)
+    }
+
+    /// Execute an approved proposal once its timelock has passed
+    pub fn execute_proposal(
+        env: Env,
+        proposal_id: u64,
+        executor: Address,
+    ) -> Result<(), GovernanceError> {
+        executor.require_auth();
+
+        GovernanceManager::execute_proposal(&env, proposal_id, executor)
+    }
+
+    /// Get proposal details
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<UpgradeProposal, GovernanceError> {
+        GovernanceManager::get_proposal(&env, proposal_id)
+    }
+
+    /// List proposal summaries from ID `start`, optionally filtered by status
+    pub fn list_proposals(
+        env: Env,
+        start: u64,
+        limit: u32,
+        status_filter: Option<ProposalStatus>,
+    ) -> ProposalPage {
+        GovernanceManager::list_proposals(&env, start, limit, status_filter)
+    }
+
+    /// List summaries of the proposals targeting a contract, oldest first
+    pub fn list_proposals_by_target(
+        env: Env,
+        target_contract: Address,
+        start: u64,
+        limit: u32,
+    ) -> ProposalPage {
+        GovernanceManager::list_proposals_by_target(&env, &target_contract, start, limit)
+    }
+
+    /// Veto a pending or approved proposal (guardian only, final)
+    pub fn veto_proposal(
+        env: Env,
+        proposal_id: u64,
+        guardian: Address,
+        reason: Symbol,
+    ) -> Result<(), GovernanceError> {
+        guardian.require_auth();
+
+        GovernanceManager::veto_proposal(&env, proposal_id, guardian, reason)
+    }
+
+    /// Mark a stale proposal as expired (callable by anyone)
+    pub fn expire_proposal(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        GovernanceManager::expire_proposal(&env, proposal_id)
+    }
+
+    /// Reject a proposal
+    pub fn reject_proposal(
+        env: Env,
+        proposal_id: u64,
+        rejector: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        rejector.require_auth();
+
+        GovernanceManager::reject_proposal(&env, proposal_id, rejector, rationale)
+    }
+
+    /// Revoke a rejection while the proposal is still pending
+    pub fn revoke_rejection(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::revoke_rejection(&env, proposal_id, approver, rationale)
+    }
+
+    /// Cancel a proposal (admin only)
+    pub fn cancel_proposal(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::cancel_proposal(&env, proposal_id, admin)
+    }
+
+    /// Halt an approved proposal (admin only)
+    pub fn halt_proposal(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+        reason: Symbol,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::halt_proposal(&env, proposal_id, admin, reason)
+    }
+
+    /// Resume a halted proposal (admin only)
+    pub fn resume_proposal(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+        new_timelock_delay: u64,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::resume_proposal(&env, proposal_id, admin, new_timelock_delay)
+    }
+
+    /// Revoke an approval
+    pub fn revoke_approval(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::revoke_approval(&env, proposal_id, approver, rationale)
+    }
+
+    /// Get time remaining until execution is possible
+    pub fn get_time_to_execution(env: Env, proposal_id: u64) -> Result<u64, GovernanceError> {
+        GovernanceManager::get_time_to_execution(&env, proposal_id)
+    }
+
+    /// Grant a governance role (admin only)
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        member: Address,
+        role: GovernanceRole,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::grant_role(&env, admin, member, role)
+    }
+
+    /// Revoke a member's governance role (admin only)
+    pub fn revoke_role(env: Env, admin: Address, member: Address) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::revoke_role(&env, admin, member)
+    }
+
+    /// Get the governance role held by an address, if any
+    pub fn get_role(env: Env, member: Address) -> Option<GovernanceRole> {
+        GovernanceManager::get_role(&env, &member)
+    }
+
+    /// List members holding a governance role
+    pub fn list_members(env: Env, role: GovernanceRole) -> Vec<Address> {
+        GovernanceManager::list_members(&env, role)
+    }
+
+    /// Decide a pending proposal by token-weighted vote instead of multisig (admin only)
+    pub fn enable_token_voting(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+        token: Address,
+        voting_period: u64,
+        quorum_bps: u32,
+        majority_bps: u32,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::enable_token_voting(
+            &env,
+            proposal_id,
+            admin,
+            token,
+            voting_period,
+            quorum_bps,
+            majority_bps,
+        )
+    }
+
+    /// Vote on a token-weighted proposal, returning the counted weight
+    pub fn cast_vote(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        choice: VoteChoice,
+    ) -> Result<i128, GovernanceError> {
+        voter.require_auth();
+
+        GovernanceManager::cast_vote(&env, proposal_id, voter, choice)
+    }
+
+    /// Close a token vote after its voting period
+    pub fn finalize_vote(env: Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        GovernanceManager::finalize_vote(&env, proposal_id)
+    }
+
+    /// Get the running tally of a token vote
+    pub fn get_vote_tally(env: Env, proposal_id: u64) -> VoteTally {
+        GovernanceManager::get_vote_tally(&env, proposal_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,257 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use academy_rewards::{AcademyRewardsContract, AcademyRewardsContractClient};
+use academy_vesting::{vesting::AcademyVestingContractClient, AcademyVestingContract};
+use shared::events::{topics, ContractUpgradedEvent};
+use shared::governance::{ProposalAction, ProposalMetadata};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events as _, testutils::Ledger as _, vec, Bytes, IntoVal,
+    String,
+};
+use token::{TokenContract, TokenContractClient};
+
+/// Minimal "v2" contract: a single `get_version` export returning `2u32`,
+/// plus the `contractenvmetav0` section declaring protocol 20.
+///
+/// (module
+///   (func (export "get_version") (result i64) i64.const 0x200000004))
+const V2_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7e, 0x03,
+    0x02, 0x01, 0x00, 0x07, 0x0f, 0x01, 0x0b, 0x67, 0x65, 0x74, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x00, 0x00, 0x0a, 0x0a, 0x01, 0x08, 0x00, 0x42, 0x84, 0x80, 0x80, 0x80, 0x20, 0x0b,
+    0x00, 0x1e, 0x11, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x65, 0x6e, 0x76, 0x6d, 0x65,
+    0x74, 0x61, 0x76, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+];
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    approver: Address,
+    executor: Address,
+    client: GovernanceContractClient<'a>,
+}
+
+fn setup() -> Setup<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register_contract(None, GovernanceContract);
+    let client = GovernanceContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let executor = Address::generate(&env);
+    client.init(&admin, &vec![&env, approver.clone()], &executor);
+
+    Setup {
+        env,
+        admin,
+        approver,
+        executor,
+        client,
+    }
+}
+
+fn set_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+fn metadata(env: &Env, title: &str) -> ProposalMetadata {
+    ProposalMetadata {
+        title: String::from_str(env, title),
+        content_hash: BytesN::from_array(env, &[7u8; 32]),
+    }
+}
+
+/// Token whose admin is the governance contract
+fn governed_token<'a>(s: &Setup) -> TokenContractClient<'a> {
+    let token_id = s.env.register_contract(None, TokenContract);
+    let token = TokenContractClient::new(&s.env, &token_id);
+    token.initialize(
+        &s.client.address,
+        &String::from_str(&s.env, "Stellara"),
+        &String::from_str(&s.env, "STLR"),
+        &7,
+    );
+    token
+}
+
+/// Approve a single-approver proposal and execute it after its timelock
+fn pass(s: &Setup, proposal_id: u64) {
+    set_timestamp(&s.env, 1000 + 3601);
+    s.client
+        .approve_proposal(&proposal_id, &s.approver, &Rationale::None);
+    set_timestamp(&s.env, 1000 + 3601 + 3600);
+    s.client.execute_proposal(&proposal_id, &s.executor);
+}
+
+#[test]
+fn test_init_assigns_roles_once() {
+    let s = setup();
+
+    assert_eq!(s.client.get_version(), 1);
+    assert_eq!(s.client.get_role(&s.admin), Some(GovernanceRole::Admin));
+    assert_eq!(
+        s.client.get_role(&s.approver),
+        Some(GovernanceRole::Approver)
+    );
+    assert_eq!(
+        s.client.get_role(&s.executor),
+        Some(GovernanceRole::Executor)
+    );
+
+    let again = s
+        .client
+        .try_init(&s.admin, &vec![&s.env, s.approver.clone()], &s.executor);
+    assert_eq!(again, Err(Ok(GovernanceError::Unauthorized)));
+}
+
+#[test]
+fn test_upgrades_a_contract_it_administers() {
+    let s = setup();
+    let token = governed_token(&s);
+    assert_eq!(token.get_version(), 1);
+
+    let hash = s
+        .env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&s.env, V2_WASM));
+    let proposal_id = s.client.propose_upgrade(
+        &s.admin,
+        &token.address,
+        &hash,
+        &metadata(&s.env, "Upgrade token to v2"),
+        &vec![&s.env, s.approver.clone()],
+        &1,
+        &3600,
+    );
+
+    // The target's current version is read from the target itself
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert_eq!(proposal.current_version, 1);
+    assert_eq!(proposal.proposed_version, 2);
+
+    pass(&s, proposal_id);
+
+    // The token now runs the v2 code
+    assert_eq!(token.get_version(), 2);
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_runs_admin_calls_on_governed_contracts() {
+    let s = setup();
+    let token = governed_token(&s);
+    let holder = Address::generate(&s.env);
+
+    let proposal_id = s.client.propose_action(
+        &s.admin,
+        &token.address,
+        &symbol_short!("mint"),
+        &vec![&s.env, holder.into_val(&s.env), 500i128.into_val(&s.env)],
+        &metadata(&s.env, "Mint to holder"),
+        &vec![&s.env, s.approver.clone()],
+        &1,
+        &3600,
+    );
+    pass(&s, proposal_id);
+
+    assert_eq!(token.balance(&holder), 500);
+}
+
+#[test]
+fn test_batch_upgrades_several_contracts_together() {
+    let s = setup();
+    let token = governed_token(&s);
+
+    let rewards_id = s.env.register_contract(None, AcademyRewardsContract);
+    let rewards = AcademyRewardsContractClient::new(&s.env, &rewards_id);
+    rewards.initialize(&s.client.address);
+    assert_eq!(rewards.get_version(), 1);
+
+    let vesting_id = s.env.register_contract(None, AcademyVestingContract);
+    let vesting = AcademyVestingContractClient::new(&s.env, &vesting_id);
+    vesting.init(&s.client.address, &token.address, &s.client.address);
+    assert_eq!(vesting.get_version(), 1);
+
+    let hash = s
+        .env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&s.env, V2_WASM));
+    let upgrade = |target: &Address| ContractCall {
+        target: target.clone(),
+        function: symbol_short!("upgrade"),
+        args: vec![&s.env, hash.into_val(&s.env), 2u32.into_val(&s.env)],
+    };
+    let proposal_id = s.client.propose_batch(
+        &s.admin,
+        &vec![
+            &s.env,
+            upgrade(&token.address),
+            upgrade(&rewards_id),
+            upgrade(&vesting_id),
+        ],
+        &metadata(&s.env, "Upgrade suite to v2"),
+        &vec![&s.env, s.approver.clone()],
+        &1,
+        &3600,
+    );
+    assert!(matches!(
+        s.client.get_proposal(&proposal_id).action,
+        ProposalAction::Batch(_)
+    ));
+
+    pass(&s, proposal_id);
+
+    assert_eq!(token.get_version(), 2);
+    assert_eq!(rewards.get_version(), 2);
+    assert_eq!(vesting.get_version(), 2);
+
+    // Every contract reports its upgrade with the same event
+    let events = s.env.events().all();
+    for target in [&token.address, &rewards_id, &vesting_id] {
+        let upgraded = (
+            target.clone(),
+            (topics::CONTRACT_UPGRADED,).into_val(&s.env),
+            ContractUpgradedEvent {
+                upgraded_by: s.client.address.clone(),
+                new_wasm_hash: hash.clone(),
+                new_version: 2,
+                timestamp: 1000 + 3601 + 3600,
+            }
+            .into_val(&s.env),
+        );
+        assert!(events.contains(&upgraded));
+    }
+    assert_eq!(
+        s.client
+            .list_proposals_by_target(&rewards_id, &0, &10)
+            .proposals
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Auth")]
+fn test_governed_contract_rejects_upgrade_from_others() {
+    let s = setup();
+    let token = governed_token(&s);
+    let hash = s
+        .env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&s.env, V2_WASM));
+
+    // Without mocked auth only the governance contract itself can authorize
+    s.env.set_auths(&[]);
+    s.env.as_contract(&token.address, || {
+        TokenContract::upgrade(s.env.clone(), hash.clone(), 2).unwrap()
+    });
+}
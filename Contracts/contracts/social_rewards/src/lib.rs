@@ -1,6 +1,13 @@
 #![no_std]
-use shared::events::{EventEmitter, RewardAddedEvent, RewardClaimedEvent};
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+use shared::admin_transfer::{AdminTransfer, AdminTransferError, PendingAdmin};
+use shared::events::{ContractUpgradedEvent, EventEmitter, RewardAddedEvent, RewardClaimedEvent};
+use shared::governance::VERSION_KEY;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol,
+};
+
+/// Version of this contract implementation
+const CONTRACT_VERSION: u32 = 1;
 
 /// Social reward record
 #[contracttype]
@@ -37,6 +44,9 @@ pub enum RewardError {
     AlreadyClaimed = 5004,
     InsufficientBalance = 5005,
     NotInitialized = 5006,
+    NoPendingAdmin = 5007,
+    NotPendingAdmin = 5008,
+    AdminTransferExpired = 5009,
 }
 
 impl From<RewardError> for soroban_sdk::Error {
@@ -51,6 +61,16 @@ impl From<&RewardError> for soroban_sdk::Error {
     }
 }
 
+impl From<AdminTransferError> for RewardError {
+    fn from(error: AdminTransferError) -> Self {
+        match error {
+            AdminTransferError::NoPendingAdmin => RewardError::NoPendingAdmin,
+            AdminTransferError::NotPendingAdmin => RewardError::NotPendingAdmin,
+            AdminTransferError::TransferExpired => RewardError::AdminTransferExpired,
+        }
+    }
+}
+
 impl From<soroban_sdk::Error> for RewardError {
     fn from(_error: soroban_sdk::Error) -> Self {
         RewardError::Unauthorized
@@ -91,9 +111,114 @@ impl SocialRewardsContract {
         let stats_key = symbol_short!("stats");
         env.storage().persistent().set(&stats_key, &stats);
 
+        // Store contract version
+        env.storage()
+            .persistent()
+            .set(&VERSION_KEY, &CONTRACT_VERSION);
+
+        Ok(())
+    }
+
+    /// Install new WASM and record its version (admin only)
+    ///
+    /// Deploy with the governance contract as admin so upgrades go through
+    /// a proposal.
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), RewardError> {
+        let admin_key = symbol_short!("admin");
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(RewardError::NotInitialized)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.storage().persistent().set(&VERSION_KEY, &new_version);
+
+        EventEmitter::contract_upgraded(
+            &env,
+            ContractUpgradedEvent {
+                upgraded_by: admin,
+                new_wasm_hash,
+                new_version,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get current contract version
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().persistent().get(&VERSION_KEY).unwrap_or(0)
+    }
+
+    /// Propose a new admin (admin only); it takes over once it accepts
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), RewardError> {
+        admin.require_auth();
+
+        // Verify caller is admin
+        let admin_key = symbol_short!("admin");
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(RewardError::NotInitialized)?;
+
+        if admin != stored_admin {
+            return Err(RewardError::Unauthorized);
+        }
+
+        AdminTransfer::propose(&env, admin, new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), RewardError> {
+        new_admin.require_auth();
+
+        let admin_key = symbol_short!("admin");
+        let previous_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(RewardError::NotInitialized)?;
+
+        AdminTransfer::accept(&env, previous_admin, &new_admin)?;
+        env.storage().persistent().set(&admin_key, &new_admin);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer (admin only)
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), RewardError> {
+        admin.require_auth();
+
+        // Verify caller is admin
+        let admin_key = symbol_short!("admin");
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&admin_key)
+            .ok_or(RewardError::NotInitialized)?;
+
+        if admin != stored_admin {
+            return Err(RewardError::Unauthorized);
+        }
+
+        AdminTransfer::cancel(&env, admin)?;
         Ok(())
     }
 
+    /// Get the pending admin transfer, if any
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        AdminTransfer::pending(&env)
+    }
+
     /// Add a reward for a user (admin only)
     pub fn add_reward(
         env: Env,
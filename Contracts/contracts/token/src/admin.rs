@@ -1,7 +1,17 @@
-use crate::storage::get_admin;
-use soroban_sdk::Env;
+use crate::storage::{get_admin, get_pauser};
+use crate::TokenError;
+use soroban_sdk::{Address, Env};
 
-pub fn require_admin(env: &Env) {
-    let admin = get_admin(env);
+/// Require the admin's authorization, returning the admin
+pub fn require_admin(env: &Env) -> Result<Address, TokenError> {
+    let admin = get_admin(env)?;
     admin.require_auth();
+    Ok(admin)
+}
+
+/// Require the pauser's authorization, returning the pauser
+pub fn require_pauser(env: &Env) -> Result<Address, TokenError> {
+    let pauser = get_pauser(env).ok_or(TokenError::PauserNotSet)?;
+    pauser.require_auth();
+    Ok(pauser)
 }
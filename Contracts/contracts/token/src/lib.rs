@@ -1,25 +1,84 @@
 #![no_std]
 
+use shared::admin_transfer::{AdminTransfer, AdminTransferError, PendingAdmin};
+use shared::events::{
+    ContractPausedEvent, ContractUnpausedEvent, ContractUpgradedEvent, EventEmitter,
+};
 use shared::state_verification::{compute_commitment, make_proof, StateProof};
 use soroban_sdk::{
-    contract, contractimpl, Address, BytesN, Env, Error, IntoVal, String, Symbol, TryFromVal, Val,
-    Vec,
+    contract, contracterror, contractimpl, Address, BytesN, Env, Error, IntoVal, String, Symbol,
+    TryFromVal, Val, Vec,
 };
 
 mod admin;
+mod permit;
 mod storage;
+mod votes;
 
+pub use storage::AuthorizationMode;
 use storage::{Allowance, TokenMetadata};
 
+/// Version of this contract implementation
+const CONTRACT_VERSION: u32 = 1;
+
+/// Token errors. Codes below 100 are the ones the Stellar Asset Contract
+/// raises for the same failures, so callers can handle both alike.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    AlreadyInitialized = 3,
+    NegativeAmount = 8,
+    AllowanceError = 9, // Allowance too small, or expiration out of range
+    BalanceError = 10,  // Balance too small
+    BalanceDeauthorized = 11,
+    Overflow = 12,
+
+    NotInitialized = 100,
+    FutureLedger = 101,
+    StateMismatch = 102,
+    UnsupportedStateKey = 103,
+    MaxSupplyExceeded = 104,
+    MaxSupplyLocked = 105,
+    NotMinter = 106,
+    MinterAllowanceExceeded = 107,
+    InvalidNonce = 108,
+    PermitKeyNotSet = 109,
+    AccountFrozen = 110,
+    ContractPaused = 111,
+    PauserNotSet = 112,
+    NoPendingAdmin = 113,
+    NotPendingAdmin = 114,
+    AdminTransferExpired = 115,
+    MaxSupplyNotSet = 116,
+    CheckpointMissing = 117,
+}
+
+impl From<AdminTransferError> for TokenError {
+    fn from(error: AdminTransferError) -> Self {
+        match error {
+            AdminTransferError::NoPendingAdmin => TokenError::NoPendingAdmin,
+            AdminTransferError::NotPendingAdmin => TokenError::NotPendingAdmin,
+            AdminTransferError::TransferExpired => TokenError::AdminTransferExpired,
+        }
+    }
+}
+
 #[contract]
 pub struct TokenContract;
 
 #[contractimpl]
 impl TokenContract {
     /// Initialize token metadata and admin.
-    pub fn initialize(env: Env, admin: Address, name: String, symbol: String, decimals: u32) {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+    ) -> Result<(), TokenError> {
         if storage::has_admin(&env) {
-            panic!("Already initialized");
+            return Err(TokenError::AlreadyInitialized);
         }
         admin.require_auth();
         storage::set_admin(&env, &admin);
@@ -31,11 +90,15 @@ impl TokenContract {
                 decimals,
             },
         );
-        storage::set_total_supply(&env, 0);
+        storage::set_total_supply(&env, 0)?;
+        storage::set_version(&env, CONTRACT_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
     }
 
     // --------- Standard token interface ---------
     pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        storage::extend_instance(&env);
         storage::get_allowance_amount(&env, &from, &spender)
     }
 
@@ -45,162 +108,537 @@ impl TokenContract {
         spender: Address,
         amount: i128,
         expiration_ledger: u32,
-    ) {
+    ) -> Result<(), TokenError> {
         from.require_auth();
-        ensure_nonnegative(amount);
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
 
-        let current_ledger = env.ledger().sequence();
-        if expiration_ledger < current_ledger && amount != 0 {
-            panic!("Invalid expiration");
-        }
+        write_approval(&env, from, spender, amount, expiration_ledger)
+    }
+
+    /// Approve on `owner`'s behalf with an ed25519 signature over
+    /// `permit_digest`, made with the key `owner` registered through
+    /// `set_permit_key`. Anyone may submit it; `nonce` must equal
+    /// `nonces(owner)`.
+    pub fn permit(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), TokenError> {
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
 
-        let allowance = Allowance {
+        permit::consume(
+            &env,
+            &owner,
+            &spender,
             amount,
             expiration_ledger,
-        };
-        storage::set_allowance(&env, &from, &spender, &allowance);
+            nonce,
+            &signature,
+        )?;
+        write_approval(&env, owner, spender, amount, expiration_ledger)
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "approve"), from, spender),
-            (amount, expiration_ledger),
-        );
+    /// Digest `owner` signs to permit `spender` to spend `amount`.
+    pub fn permit_digest(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+    ) -> BytesN<32> {
+        permit::digest(&env, &owner, &spender, amount, expiration_ledger, nonce)
+    }
+
+    /// Register the ed25519 key `owner` signs permits with, replacing any
+    /// previous key and voiding permits signed with it.
+    pub fn set_permit_key(env: Env, owner: Address, public_key: BytesN<32>) {
+        owner.require_auth();
+        storage::extend_instance(&env);
+
+        permit::set_key(&env, &owner, &public_key);
+        env.events()
+            .publish((Symbol::new(&env, "permit_key_set"), owner), public_key);
+    }
+
+    /// Key `owner` signs permits with, if one is registered.
+    pub fn permit_key(env: Env, owner: Address) -> Option<BytesN<32>> {
+        permit::key(&env, &owner)
+    }
+
+    /// Nonce `owner`'s next permit must be signed over.
+    pub fn nonces(env: Env, owner: Address) -> u64 {
+        permit::nonce(&env, &owner)
     }
 
     pub fn balance(env: Env, id: Address) -> i128 {
+        storage::extend_instance(&env);
         storage::balance_of(&env, &id)
     }
 
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), TokenError> {
         from.require_auth();
-        ensure_nonnegative(amount);
-        require_authorized(&env, &from);
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+        require_active(&env, &from)?;
 
-        internal_transfer(&env, &from, &to, amount);
+        internal_transfer(&env, &from, &to, amount)
     }
 
-    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
         spender.require_auth();
-        ensure_nonnegative(amount);
-        require_authorized(&env, &from);
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+        require_active(&env, &from)?;
 
-        spend_allowance(&env, &from, &spender, amount);
-        internal_transfer(&env, &from, &to, amount);
+        spend_allowance(&env, &from, &spender, amount)?;
+        internal_transfer(&env, &from, &to, amount)
     }
 
-    pub fn burn(env: Env, from: Address, amount: i128) {
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
         from.require_auth();
-        ensure_nonnegative(amount);
-        require_authorized(&env, &from);
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+        require_active(&env, &from)?;
 
-        burn_balance(&env, &from, amount);
+        burn_balance(&env, &from, amount)?;
         env.events()
             .publish((Symbol::new(&env, "burn"), from), amount);
+        Ok(())
     }
 
-    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+    pub fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
         spender.require_auth();
-        ensure_nonnegative(amount);
-        require_authorized(&env, &from);
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+        require_active(&env, &from)?;
 
-        spend_allowance(&env, &from, &spender, amount);
-        burn_balance(&env, &from, amount);
+        spend_allowance(&env, &from, &spender, amount)?;
+        burn_balance(&env, &from, amount)?;
         env.events()
             .publish((Symbol::new(&env, "burn"), from), amount);
+        Ok(())
+    }
+
+    pub fn decimals(env: Env) -> Result<u32, TokenError> {
+        storage::extend_instance(&env);
+        Ok(storage::get_metadata(&env)?.decimals)
+    }
+
+    pub fn name(env: Env) -> Result<String, TokenError> {
+        storage::extend_instance(&env);
+        Ok(storage::get_metadata(&env)?.name)
+    }
+
+    pub fn symbol(env: Env) -> Result<String, TokenError> {
+        storage::extend_instance(&env);
+        Ok(storage::get_metadata(&env)?.symbol)
+    }
+
+    // --------- Delegation ---------
+    /// Delegate the voting power of `from`'s balance to `to`. Delegating to
+    /// oneself restores the default.
+    pub fn delegate(env: Env, from: Address, to: Address) -> Result<(), TokenError> {
+        from.require_auth();
+        votes::set_delegate(&env, &from, &to)
     }
 
-    pub fn decimals(env: Env) -> u32 {
-        storage::get_metadata(&env).decimals
+    pub fn delegates(env: Env, id: Address) -> Address {
+        votes::delegate_of(&env, &id)
     }
 
-    pub fn name(env: Env) -> String {
-        storage::get_metadata(&env).name
+    pub fn voting_power(env: Env, id: Address) -> i128 {
+        votes::voting_power(&env, &id)
     }
 
-    pub fn symbol(env: Env) -> String {
-        storage::get_metadata(&env).symbol
+    /// Voting power of `id` as of the end of `ledger`.
+    pub fn voting_power_at(env: Env, id: Address, ledger: u32) -> Result<i128, TokenError> {
+        ensure_past_or_current(&env, ledger)?;
+        votes::voting_power_at(&env, &id, ledger)
     }
 
     // --------- Admin interface ---------
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let current_admin = storage::get_admin(&env);
-        current_admin.require_auth();
-        storage::set_admin(&env, &new_admin);
-        env.events()
-            .publish((Symbol::new(&env, "set_admin"), current_admin), new_admin);
+    /// Kept under the Stellar Asset Contract's name for existing tooling,
+    /// but only proposes `new_admin`: it takes over once it calls
+    /// `accept_admin`, as with `propose_admin`.
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), TokenError> {
+        Self::propose_admin(env, new_admin)
     }
 
-    pub fn admin(env: Env) -> Address {
+    pub fn admin(env: Env) -> Result<Address, TokenError> {
         storage::get_admin(&env)
     }
 
-    pub fn set_authorized(env: Env, id: Address, authorize: bool) {
-        admin::require_admin(&env);
+    /// Propose `new_admin` as the next admin. It takes over once it calls
+    /// `accept_admin`, within the acceptance window.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        AdminTransfer::propose(&env, admin, new_admin);
+        Ok(())
+    }
+
+    /// Take over as admin (proposed admin only)
+    pub fn accept_admin(env: Env) -> Result<(), TokenError> {
+        let pending = AdminTransfer::pending(&env).ok_or(TokenError::NoPendingAdmin)?;
+        pending.admin.require_auth();
+        storage::extend_instance(&env);
+
+        let previous_admin = storage::get_admin(&env)?;
+        AdminTransfer::accept(&env, previous_admin.clone(), &pending.admin)?;
+        storage::set_admin(&env, &pending.admin);
+        env.events().publish(
+            (Symbol::new(&env, "set_admin"), previous_admin),
+            pending.admin,
+        );
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer (admin only)
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        AdminTransfer::cancel(&env, admin)?;
+        Ok(())
+    }
+
+    pub fn pending_admin(env: Env) -> Option<PendingAdmin> {
+        AdminTransfer::pending(&env)
+    }
+
+    /// Install new WASM and record its version. Called by the governance
+    /// contract once it holds the admin role.
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        storage::set_version(&env, new_version);
+        EventEmitter::contract_upgraded(
+            &env,
+            ContractUpgradedEvent {
+                upgraded_by: admin,
+                new_wasm_hash,
+                new_version,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
+    pub fn set_authorized(env: Env, id: Address, authorize: bool) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
         storage::set_authorized(&env, &id, authorize);
         env.events()
-            .publish((Symbol::new(&env, "set_authorized"), id), authorize);
+            .publish((Symbol::new(&env, "set_authorized"), admin, id), authorize);
+        Ok(())
     }
 
     pub fn authorized(env: Env, id: Address) -> bool {
+        storage::extend_instance(&env);
         storage::get_authorized(&env, &id)
     }
 
-    pub fn mint(env: Env, to: Address, amount: i128) {
-        admin::require_admin(&env);
-        ensure_nonnegative(amount);
+    /// Choose how `set_authorized` flags apply. Defaults to `Blocklist`.
+    pub fn set_authorization_mode(env: Env, mode: AuthorizationMode) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        storage::set_authorization_mode(&env, mode);
+        env.events()
+            .publish((Symbol::new(&env, "set_auth_mode"), admin), mode);
+        Ok(())
+    }
 
-        let balance = storage::balance_of(&env, &to);
-        let new_balance = balance.checked_add(amount).expect("Overflow");
-        storage::set_balance(&env, &to, &new_balance);
+    pub fn authorization_mode(env: Env) -> AuthorizationMode {
+        storage::authorization_mode(&env)
+    }
 
-        let supply = storage::total_supply(&env);
-        let new_supply = supply.checked_add(amount).expect("Overflow");
-        storage::set_total_supply(&env, new_supply);
+    /// Freeze `id` for a compliance hold: it can neither send, receive nor
+    /// spend allowances until unfrozen, whatever the authorization mode.
+    /// The admin can still claw back its balance.
+    pub fn freeze(env: Env, id: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        storage::set_frozen(&env, &id, true);
+        env.events()
+            .publish((Symbol::new(&env, "freeze"), admin, id), ());
+        Ok(())
+    }
 
+    pub fn unfreeze(env: Env, id: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        storage::set_frozen(&env, &id, false);
+        env.events()
+            .publish((Symbol::new(&env, "unfreeze"), admin, id), ());
+        Ok(())
+    }
+
+    pub fn is_frozen(env: Env, id: Address) -> bool {
+        storage::is_frozen(&env, &id)
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), TokenError> {
         // Optimized: Cache admin address to avoid redundant storage read
-        let admin_addr = storage::get_admin(&env);
+        let admin_addr = admin::require_admin(&env)?;
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+
+        mint_balance(&env, &to, amount)?;
+        env.events()
+            .publish((Symbol::new(&env, "mint"), admin_addr, to), amount);
+        Ok(())
+    }
+
+    pub fn clawback(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+
+        burn_balance(&env, &from, amount)?;
+        env.events()
+            .publish((Symbol::new(&env, "clawback"), admin, from), amount);
+        Ok(())
+    }
+
+    // --------- Pausing ---------
+    /// Hand the pauser role to `pauser`. The pauser is kept separate from
+    /// the admin so an operations key can halt the token without holding
+    /// admin rights.
+    pub fn set_pauser(env: Env, pauser: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        storage::set_pauser(&env, &pauser);
+        env.events()
+            .publish((Symbol::new(&env, "set_pauser"), admin), pauser);
+        Ok(())
+    }
+
+    pub fn pauser(env: Env) -> Option<Address> {
+        storage::get_pauser(&env)
+    }
+
+    /// Halt transfers, burns and mints (pauser only). Admin clawback still
+    /// works while paused.
+    pub fn pause(env: Env) -> Result<(), TokenError> {
+        let pauser = admin::require_pauser(&env)?;
+        storage::extend_instance(&env);
+        storage::set_paused(&env, true);
+        EventEmitter::contract_paused(
+            &env,
+            ContractPausedEvent {
+                paused_by: pauser,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Resume transfers, burns and mints (pauser only)
+    pub fn unpause(env: Env) -> Result<(), TokenError> {
+        let pauser = admin::require_pauser(&env)?;
+        storage::extend_instance(&env);
+        storage::set_paused(&env, false);
+        EventEmitter::contract_unpaused(
+            &env,
+            ContractUnpausedEvent {
+                unpaused_by: pauser,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    // --------- Supply cap and minters ---------
+    /// Cap total supply at `max_supply`, or lift the cap with `None`. The
+    /// cap may not be set below the current supply, and cannot change once
+    /// locked.
+    pub fn set_max_supply(env: Env, max_supply: Option<i128>) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        if storage::is_max_supply_locked(&env) {
+            return Err(TokenError::MaxSupplyLocked);
+        }
+        if let Some(max) = max_supply {
+            ensure_nonnegative(max)?;
+            if max < storage::total_supply(&env) {
+                return Err(TokenError::MaxSupplyExceeded);
+            }
+        }
+
+        storage::set_max_supply(&env, max_supply);
+        env.events()
+            .publish((Symbol::new(&env, "max_supply_set"), admin), max_supply);
+        Ok(())
+    }
+
+    /// Make the current supply cap permanent. A cap must be set first.
+    pub fn lock_max_supply(env: Env) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+        if storage::is_max_supply_locked(&env) {
+            return Err(TokenError::MaxSupplyLocked);
+        }
+        if storage::max_supply(&env).is_none() {
+            return Err(TokenError::MaxSupplyNotSet);
+        }
+
+        storage::lock_max_supply(&env);
         env.events().publish(
-            (Symbol::new(&env, "mint"), admin_addr, to),
-            amount,
+            (Symbol::new(&env, "max_supply_locked"), admin),
+            storage::max_supply(&env),
         );
+        Ok(())
+    }
+
+    pub fn max_supply(env: Env) -> Option<i128> {
+        storage::max_supply(&env)
+    }
+
+    pub fn is_max_supply_locked(env: Env) -> bool {
+        storage::is_max_supply_locked(&env)
     }
 
-    pub fn clawback(env: Env, from: Address, amount: i128) {
-        admin::require_admin(&env);
-        ensure_nonnegative(amount);
+    /// Let `minter` mint up to `allowance` tokens, replacing any previous
+    /// allowance.
+    pub fn add_minter(env: Env, minter: Address, allowance: i128) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        ensure_nonnegative(allowance)?;
+        storage::extend_instance(&env);
 
-        burn_balance(&env, &from, amount);
+        let mut minters = storage::minters(&env);
+        minters.set(minter.clone(), allowance);
+        storage::set_minters(&env, &minters);
         env.events().publish(
-            (
-                Symbol::new(&env, "clawback"),
-                storage::get_admin(&env),
-                from,
-            ),
-            amount,
+            (Symbol::new(&env, "minter_added"), admin, minter),
+            allowance,
         );
+        Ok(())
+    }
+
+    pub fn remove_minter(env: Env, minter: Address) -> Result<(), TokenError> {
+        let admin = admin::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        let mut minters = storage::minters(&env);
+        if minters.remove(minter.clone()).is_none() {
+            return Err(TokenError::NotMinter);
+        }
+        storage::set_minters(&env, &minters);
+        env.events()
+            .publish((Symbol::new(&env, "minter_removed"), admin, minter), ());
+        Ok(())
+    }
+
+    pub fn is_minter(env: Env, minter: Address) -> bool {
+        storage::minters(&env).contains_key(minter)
+    }
+
+    /// Amount `minter` may still mint (0 for non-minters).
+    pub fn minter_allowance(env: Env, minter: Address) -> i128 {
+        storage::minters(&env).get(minter).unwrap_or(0)
+    }
+
+    pub fn minters(env: Env) -> Vec<Address> {
+        storage::minters(&env).keys()
+    }
+
+    /// Mint `amount` to `to` against `minter`'s allowance.
+    pub fn minter_mint(
+        env: Env,
+        minter: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
+        minter.require_auth();
+        ensure_nonnegative(amount)?;
+        storage::extend_instance(&env);
+        require_not_paused(&env)?;
+
+        let mut minters = storage::minters(&env);
+        let allowance = minters.get(minter.clone()).ok_or(TokenError::NotMinter)?;
+        if amount > allowance {
+            return Err(TokenError::MinterAllowanceExceeded);
+        }
+        minters.set(minter.clone(), allowance - amount);
+        storage::set_minters(&env, &minters);
+
+        mint_balance(&env, &to, amount)?;
+        env.events()
+            .publish((Symbol::new(&env, "mint"), minter, to), amount);
+        Ok(())
     }
 
     // --------- Additional helpers ---------
     pub fn total_supply(env: Env) -> i128 {
+        storage::extend_instance(&env);
         storage::total_supply(&env)
     }
 
-    pub fn state_commitment(env: Env, key: Symbol, subject: Val) -> BytesN<32> {
+    /// Balance of `id` as of the end of `ledger`.
+    pub fn balance_at(env: Env, id: Address, ledger: u32) -> Result<i128, TokenError> {
+        ensure_past_or_current(&env, ledger)?;
+        storage::balance_at(&env, &id, ledger)
+    }
+
+    /// Total supply as of the end of `ledger`.
+    pub fn total_supply_at(env: Env, ledger: u32) -> Result<i128, TokenError> {
+        ensure_past_or_current(&env, ledger)?;
+        storage::total_supply_at(&env, ledger)
+    }
+
+    pub fn state_commitment(env: Env, key: Symbol, subject: Val) -> Result<BytesN<32>, TokenError> {
         let k = Symbol::new(&env, "balance");
         if key == k {
-            let tuple: (Address, i128) = <(Address, i128)>::try_from_val(&env, &subject).unwrap();
+            let tuple: (Address, i128) = <(Address, i128)>::try_from_val(&env, &subject)
+                .map_err(|_| TokenError::StateMismatch)?;
             let actual = storage::balance_of(&env, &tuple.0);
             if actual != tuple.1 {
-                panic!("MISMATCH");
+                return Err(TokenError::StateMismatch);
             }
-            return compute_commitment(
+            return Ok(compute_commitment(
                 &env,
                 &env.current_contract_address(),
                 &key,
                 &subject,
                 env.ledger().sequence(),
-            );
+            ));
         }
-        panic!("UNSUPPORTED");
+        Err(TokenError::UnsupportedStateKey)
     }
 
     pub fn get_balance_proof(env: Env, id: Address) -> StateProof {
@@ -215,19 +653,84 @@ impl TokenContract {
     }
 }
 
-fn ensure_nonnegative(amount: i128) {
+fn ensure_nonnegative(amount: i128) -> Result<(), TokenError> {
     if amount < 0 {
-        panic!("Negative amount");
+        return Err(TokenError::NegativeAmount);
+    }
+    Ok(())
+}
+
+fn ensure_past_or_current(env: &Env, ledger: u32) -> Result<(), TokenError> {
+    if ledger > env.ledger().sequence() {
+        return Err(TokenError::FutureLedger);
     }
+    Ok(())
 }
 
-fn require_authorized(env: &Env, id: &Address) {
+fn require_not_paused(env: &Env) -> Result<(), TokenError> {
+    if storage::is_paused(env) {
+        return Err(TokenError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fail unless `id` may send and receive: not frozen, and authorized under
+/// the current mode.
+fn require_active(env: &Env, id: &Address) -> Result<(), TokenError> {
+    require_not_frozen(env, id)?;
     if !storage::get_authorized(env, id) {
-        panic!("Unauthorized");
+        return Err(TokenError::BalanceDeauthorized);
+    }
+    Ok(())
+}
+
+fn require_not_frozen(env: &Env, id: &Address) -> Result<(), TokenError> {
+    if storage::is_frozen(env, id) {
+        return Err(TokenError::AccountFrozen);
+    }
+    Ok(())
+}
+
+fn write_approval(
+    env: &Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), TokenError> {
+    let current_ledger = env.ledger().sequence();
+    if (expiration_ledger < current_ledger && amount != 0)
+        || expiration_ledger > env.ledger().max_live_until_ledger()
+    {
+        return Err(TokenError::AllowanceError);
     }
+
+    let allowance = Allowance {
+        amount,
+        expiration_ledger,
+    };
+    storage::set_allowance(env, &from, &spender, &allowance);
+
+    env.events().publish(
+        (Symbol::new(env, "approve"), from, spender),
+        (amount, expiration_ledger),
+    );
+    Ok(())
 }
 
-fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+/// Unwrap checked arithmetic, failing with `TokenError::Overflow`.
+pub(crate) fn checked(value: Option<i128>) -> Result<i128, TokenError> {
+    value.ok_or(TokenError::Overflow)
+}
+
+fn spend_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), TokenError> {
+    require_not_frozen(env, spender)?;
+
     // Optimized: Single storage read for allowance with expiration check
     let allowance = storage::get_allowance(env, from, spender);
     let current_ledger = env.ledger().sequence();
@@ -235,67 +738,101 @@ fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
     // Check expiration inline to avoid extra storage read
     if allowance.expiration_ledger < current_ledger {
         if amount > 0 {
-            panic!("Allowance exceeded");
+            return Err(TokenError::AllowanceError);
         }
-        return;
+        return Ok(());
     }
 
     if amount > allowance.amount {
-        panic!("Allowance exceeded");
+        return Err(TokenError::AllowanceError);
     }
 
     // Only update if amount > 0 to save gas
     if amount > 0 {
-        let remaining = allowance.amount.checked_sub(amount).expect("Overflow");
+        let remaining = checked(allowance.amount.checked_sub(amount))?;
         let updated = Allowance {
             amount: remaining,
             expiration_ledger: allowance.expiration_ledger,
         };
         storage::set_allowance(env, from, spender, &updated);
     }
+    Ok(())
+}
+
+/// Store a new balance and keep the holder's delegate in step.
+fn write_balance(env: &Env, id: &Address, previous: i128, amount: i128) -> Result<(), TokenError> {
+    votes::apply_balance_change(env, id, amount - previous)?;
+    storage::set_balance(env, id, &amount)
 }
 
-fn burn_balance(env: &Env, from: &Address, amount: i128) {
+/// Credit `amount` of new supply to `to`, within the supply cap.
+fn mint_balance(env: &Env, to: &Address, amount: i128) -> Result<(), TokenError> {
+    require_active(env, to)?;
+
+    let supply = storage::total_supply(env);
+    let new_supply = checked(supply.checked_add(amount))?;
+    if storage::max_supply(env).is_some_and(|max| new_supply > max) {
+        return Err(TokenError::MaxSupplyExceeded);
+    }
+
+    let balance = storage::balance_of(env, to);
+    let new_balance = checked(balance.checked_add(amount))?;
+    write_balance(env, to, balance, new_balance)?;
+    storage::set_total_supply(env, new_supply)
+}
+
+fn burn_balance(env: &Env, from: &Address, amount: i128) -> Result<(), TokenError> {
     let balance = storage::balance_of(env, from);
     if amount > balance {
-        panic!("Insufficient balance");
+        return Err(TokenError::BalanceError);
     }
 
-    let new_balance = balance.checked_sub(amount).expect("Overflow");
-    storage::set_balance(env, from, &new_balance);
+    let new_balance = checked(balance.checked_sub(amount))?;
+    write_balance(env, from, balance, new_balance)?;
 
     let supply = storage::total_supply(env);
-    let new_supply = supply.checked_sub(amount).expect("Overflow");
-    storage::set_total_supply(env, new_supply);
+    let new_supply = checked(supply.checked_sub(amount))?;
+    storage::set_total_supply(env, new_supply)
 }
 
-fn internal_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
-    if amount == 0 || from == to {
-        return;
-    }
+/// Move `amount` from `from` to `to`, which must be able to receive. Like
+/// the Stellar Asset Contract, zero and self transfers still check the
+/// balance and emit `transfer`.
+fn internal_transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), TokenError> {
+    require_active(env, to)?;
 
     // Optimized: Read both balances in single batch operation context
     let from_balance = storage::balance_of(env, from);
     if amount > from_balance {
-        panic!("Insufficient balance");
+        return Err(TokenError::BalanceError);
     }
 
-    let to_balance = storage::balance_of(env, to);
+    let moved = amount != 0 && from != to;
+    if moved {
+        let to_balance = storage::balance_of(env, to);
 
-    // Calculate new balances
-    let new_from = from_balance.checked_sub(amount).expect("Overflow");
-    let new_to = to_balance.checked_add(amount).expect("Overflow");
+        // Calculate new balances
+        let new_from = checked(from_balance.checked_sub(amount))?;
+        let new_to = checked(to_balance.checked_add(amount))?;
 
-    // Optimized: Batch storage writes
-    storage::set_balance(env, from, &new_from);
-    storage::set_balance(env, to, &new_to);
+        // Optimized: Batch storage writes
+        write_balance(env, from, from_balance, new_from)?;
+        write_balance(env, to, to_balance, new_to)?;
+    }
 
     env.events()
         .publish((Symbol::new(env, "transfer"), from, to), amount);
 
-    invoke_transfer_hook(env, from, to, amount);
+    if moved {
+        invoke_transfer_hook(env, from, to, amount);
+    }
+    Ok(())
 }
-
 fn invoke_transfer_hook(env: &Env, from: &Address, to: &Address, amount: i128) {
     let func = Symbol::new(env, "on_token_transfer");
     let mut args = Vec::new(env);
@@ -0,0 +1,80 @@
+use crate::storage::{self, DataKey};
+use crate::TokenError;
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+// Permits let an owner approve a spender by signing offline, so a relayer
+// can submit the approval for an account that holds no XLM. The owner
+// first registers an ed25519 permit key, which works for account and
+// contract owners alike; registering a new key voids permits signed with
+// the old one. The digest covers the network, this contract and the
+// owner's nonce, and the nonce advances with every accepted permit, so a
+// signature is valid once, here only.
+
+pub const PERMIT_DOMAIN: &[u8] = b"stellara.token.permit.v1";
+
+pub fn nonce(env: &Env, owner: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PermitNonce(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn key(env: &Env, owner: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PermitKey(owner.clone()))
+}
+
+pub fn set_key(env: &Env, owner: &Address, public_key: &BytesN<32>) {
+    let key = DataKey::PermitKey(owner.clone());
+    env.storage().persistent().set(&key, public_key);
+    storage::extend_persistent(env, &key);
+}
+
+/// sha256(domain || network id || contract || owner || spender || amount
+/// || expiration ledger || nonce)
+pub fn digest(
+    env: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<32> {
+    let mut message = Bytes::from_slice(env, PERMIT_DOMAIN);
+    message.append(&env.ledger().network_id().into());
+    message.append(&env.current_contract_address().to_xdr(env));
+    message.append(&owner.clone().to_xdr(env));
+    message.append(&spender.clone().to_xdr(env));
+    message.extend_from_array(&amount.to_be_bytes());
+    message.extend_from_array(&expiration_ledger.to_be_bytes());
+    message.extend_from_array(&nonce.to_be_bytes());
+    env.crypto().sha256(&message)
+}
+
+/// Check `signature` is made with the owner's permit key over the permit
+/// and consume the nonce. A signature that does not verify traps.
+pub fn consume(
+    env: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+    signature: &BytesN<64>,
+) -> Result<(), TokenError> {
+    if nonce != self::nonce(env, owner) {
+        return Err(TokenError::InvalidNonce);
+    }
+
+    let public_key = key(env, owner).ok_or(TokenError::PermitKeyNotSet)?;
+    let digest = digest(env, owner, spender, amount, expiration_ledger, nonce);
+    env.crypto()
+        .ed25519_verify(&public_key, &digest.into(), signature);
+    storage::extend_persistent(env, &DataKey::PermitKey(owner.clone()));
+
+    let key = DataKey::PermitNonce(owner.clone());
+    env.storage().persistent().set(&key, &(nonce + 1));
+    storage::extend_persistent(env, &key);
+    Ok(())
+}
@@ -1,4 +1,14 @@
-use soroban_sdk::{contracttype, Address, Env, String};
+use crate::TokenError;
+use soroban_sdk::{contracttype, Address, Env, Map, String};
+
+// TTLs follow the Stellar Asset Contract: the instance (admin, metadata,
+// supply) is extended for a week on every call, and account entries for 30
+// days whenever they are read or written.
+const DAY_IN_LEDGERS: u32 = 17_280;
+pub const INSTANCE_EXTEND_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_TTL_THRESHOLD: u32 = INSTANCE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+pub const BALANCE_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const BALANCE_TTL_THRESHOLD: u32 = BALANCE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -22,6 +32,25 @@ pub struct TokenMetadata {
     pub decimals: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub amount: i128,
+}
+
+/// How `set_authorized` flags apply to transfers
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuthorizationMode {
+    /// Flags are ignored; every account may hold and move tokens
+    Open,
+    /// Only accounts explicitly authorized may hold and move tokens
+    Allowlist,
+    /// Accounts may hold and move tokens unless deauthorized
+    Blocklist,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -30,6 +59,36 @@ pub enum DataKey {
     Balance(Address),
     Allowance(AllowanceKey),
     Authorized(Address),
+    BalanceCheckpoint(Address, u32),
+    BalanceCheckpointCount(Address),
+    SupplyCheckpoint(u32),
+    SupplyCheckpointCount,
+    Delegate(Address),
+    VotingPowerCheckpoint(Address, u32),
+    VotingPowerCheckpointCount(Address),
+    Version,
+    MaxSupply,
+    MaxSupplyLocked,
+    Minters,
+    PermitNonce(Address),
+    PermitKey(Address),
+    AuthorizationMode,
+    Frozen(Address),
+    Pauser,
+    Paused,
+}
+
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_EXTEND_AMOUNT);
+}
+
+/// Extend a persistent account entry. The entry must exist.
+pub fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
 }
 
 pub fn has_admin(env: &Env) -> bool {
@@ -40,26 +99,61 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
 
-pub fn get_admin(env: &Env) -> Address {
+pub fn get_admin(env: &Env) -> Result<Address, TokenError> {
     env.storage()
         .instance()
         .get(&DataKey::Admin)
-        .expect("Admin not set")
+        .ok_or(TokenError::NotInitialized)
+}
+
+pub fn get_pauser(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Pauser)
+}
+
+pub fn set_pauser(env: &Env, pauser: &Address) {
+    env.storage().instance().set(&DataKey::Pauser, pauser);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::Version, &version);
 }
 
 pub fn set_metadata(env: &Env, metadata: &TokenMetadata) {
     env.storage().instance().set(&DataKey::Metadata, metadata);
 }
 
-pub fn get_metadata(env: &Env) -> TokenMetadata {
+pub fn get_metadata(env: &Env) -> Result<TokenMetadata, TokenError> {
     env.storage()
         .instance()
         .get(&DataKey::Metadata)
-        .expect("Metadata not set")
+        .ok_or(TokenError::NotInitialized)
 }
 
-pub fn set_total_supply(env: &Env, total: i128) {
+pub fn set_total_supply(env: &Env, total: i128) -> Result<(), TokenError> {
+    let previous = total_supply(env);
     env.storage().instance().set(&DataKey::TotalSupply, &total);
+    write_checkpoint(
+        env,
+        &DataKey::SupplyCheckpointCount,
+        DataKey::SupplyCheckpoint,
+        previous,
+        total,
+    )
 }
 
 pub fn total_supply(env: &Env) -> i128 {
@@ -69,23 +163,73 @@ pub fn total_supply(env: &Env) -> i128 {
         .unwrap_or(0)
 }
 
-pub fn balance_of(env: &Env, id: &Address) -> i128 {
+pub fn max_supply(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&DataKey::MaxSupply)
+}
+
+pub fn set_max_supply(env: &Env, max_supply: Option<i128>) {
+    match max_supply {
+        Some(max) => env.storage().instance().set(&DataKey::MaxSupply, &max),
+        None => env.storage().instance().remove(&DataKey::MaxSupply),
+    }
+}
+
+pub fn is_max_supply_locked(env: &Env) -> bool {
     env.storage()
-        .persistent()
-        .get(&DataKey::Balance(id.clone()))
-        .unwrap_or(0)
+        .instance()
+        .get(&DataKey::MaxSupplyLocked)
+        .unwrap_or(false)
 }
 
-pub fn set_balance(env: &Env, id: &Address, amount: &i128) {
+pub fn lock_max_supply(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxSupplyLocked, &true);
+}
+
+/// Minters and the amount each may still mint
+pub fn minters(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Minters)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_minters(env: &Env, minters: &Map<Address, i128>) {
+    env.storage().instance().set(&DataKey::Minters, minters);
+}
+
+pub fn balance_of(env: &Env, id: &Address) -> i128 {
+    let key = DataKey::Balance(id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(balance) => {
+            extend_persistent(env, &key);
+            balance
+        }
+        None => 0,
+    }
+}
+
+pub fn set_balance(env: &Env, id: &Address, amount: &i128) -> Result<(), TokenError> {
+    let previous = balance_of(env, id);
+    write_checkpoint(
+        env,
+        &DataKey::BalanceCheckpointCount(id.clone()),
+        |index| DataKey::BalanceCheckpoint(id.clone(), index),
+        previous,
+        *amount,
+    )?;
+
     if *amount == 0 {
         env.storage()
             .persistent()
             .remove(&DataKey::Balance(id.clone()));
     } else {
-        env.storage()
-            .persistent()
-            .set(&DataKey::Balance(id.clone()), amount);
+        let key = DataKey::Balance(id.clone());
+        env.storage().persistent().set(&key, amount);
+        extend_persistent(env, &key);
     }
+    Ok(())
 }
 
 pub fn set_allowance(env: &Env, from: &Address, spender: &Address, allowance: &Allowance) {
@@ -94,6 +238,15 @@ pub fn set_allowance(env: &Env, from: &Address, spender: &Address, allowance: &A
         spender: spender.clone(),
     });
     env.storage().persistent().set(&key, allowance);
+
+    // Keep the allowance alive until it expires
+    let current_ledger = env.ledger().sequence();
+    if allowance.amount > 0 && allowance.expiration_ledger > current_ledger {
+        let live_for = allowance.expiration_ledger - current_ledger;
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, live_for, live_for);
+    }
 }
 
 pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> Allowance {
@@ -118,14 +271,175 @@ pub fn get_allowance_amount(env: &Env, from: &Address, spender: &Address) -> i12
 }
 
 pub fn set_authorized(env: &Env, id: &Address, authorized: bool) {
+    let key = DataKey::Authorized(id.clone());
+    env.storage().persistent().set(&key, &authorized);
+    extend_persistent(env, &key);
+}
+
+/// Whether `id` is authorized under the current mode
+pub fn get_authorized(env: &Env, id: &Address) -> bool {
+    let flag = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Authorized(id.clone()));
+    match authorization_mode(env) {
+        AuthorizationMode::Open => true,
+        AuthorizationMode::Allowlist => flag.unwrap_or(false),
+        AuthorizationMode::Blocklist => flag.unwrap_or(true),
+    }
+}
+
+pub fn authorization_mode(env: &Env) -> AuthorizationMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::AuthorizationMode)
+        .unwrap_or(AuthorizationMode::Blocklist)
+}
+
+pub fn set_authorization_mode(env: &Env, mode: AuthorizationMode) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AuthorizationMode, &mode);
+}
+
+pub fn is_frozen(env: &Env, id: &Address) -> bool {
     env.storage()
         .persistent()
-        .set(&DataKey::Authorized(id.clone()), &authorized);
+        .get(&DataKey::Frozen(id.clone()))
+        .unwrap_or(false)
 }
 
-pub fn get_authorized(env: &Env, id: &Address) -> bool {
+pub fn set_frozen(env: &Env, id: &Address, frozen: bool) {
+    let key = DataKey::Frozen(id.clone());
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Balance of `id` at the end of `ledger`.
+pub fn balance_at(env: &Env, id: &Address, ledger: u32) -> Result<i128, TokenError> {
+    let checkpointed = read_checkpoint(
+        env,
+        &DataKey::BalanceCheckpointCount(id.clone()),
+        |index| DataKey::BalanceCheckpoint(id.clone(), index),
+        ledger,
+    )?;
+    Ok(checkpointed.unwrap_or_else(|| balance_of(env, id)))
+}
+
+/// Total supply at the end of `ledger`.
+pub fn total_supply_at(env: &Env, ledger: u32) -> Result<i128, TokenError> {
+    let checkpointed = read_checkpoint(
+        env,
+        &DataKey::SupplyCheckpointCount,
+        DataKey::SupplyCheckpoint,
+        ledger,
+    )?;
+    Ok(checkpointed.unwrap_or_else(|| total_supply(env)))
+}
+
+pub fn checkpoint_count(env: &Env, count_key: &DataKey) -> u32 {
+    env.storage().persistent().get(count_key).unwrap_or(0)
+}
+
+/// Load a checkpoint below the recorded count and extend its TTL, so
+/// history that is still read does not expire.
+pub fn load_checkpoint(env: &Env, key: &DataKey) -> Result<Checkpoint, TokenError> {
+    let checkpoint = env
+        .storage()
+        .persistent()
+        .get(key)
+        .ok_or(TokenError::CheckpointMissing)?;
+    extend_persistent(env, key);
+    Ok(checkpoint)
+}
+
+/// Record `amount` as the value at the current ledger. Multiple changes in
+/// one ledger collapse into a single checkpoint. Values that predate
+/// checkpointing are recorded at ledger 0 so history stays correct for
+/// balances that existed before the first write.
+pub fn write_checkpoint<F>(
+    env: &Env,
+    count_key: &DataKey,
+    key: F,
+    previous: i128,
+    amount: i128,
+) -> Result<(), TokenError>
+where
+    F: Fn(u32) -> DataKey,
+{
+    let ledger = env.ledger().sequence();
+    let mut count = checkpoint_count(env, count_key);
+
+    if count == 0 && previous != 0 {
+        env.storage().persistent().set(
+            &key(0),
+            &Checkpoint {
+                ledger: 0,
+                amount: previous,
+            },
+        );
+        env.storage().persistent().set(count_key, &1u32);
+        extend_persistent(env, &key(0));
+        count = 1;
+    }
+
+    if count > 0 {
+        let last = load_checkpoint(env, &key(count - 1))?;
+        if last.ledger == ledger {
+            env.storage()
+                .persistent()
+                .set(&key(count - 1), &Checkpoint { ledger, amount });
+            extend_persistent(env, &key(count - 1));
+            extend_persistent(env, count_key);
+            return Ok(());
+        }
+    }
+
     env.storage()
         .persistent()
-        .get(&DataKey::Authorized(id.clone()))
-        .unwrap_or(true)
+        .set(&key(count), &Checkpoint { ledger, amount });
+    env.storage().persistent().set(count_key, &(count + 1));
+    extend_persistent(env, &key(count));
+    extend_persistent(env, count_key);
+    Ok(())
+}
+
+/// Binary search for the last checkpoint at or before `ledger`. Returns
+/// `None` when no checkpoint has been written yet, meaning the current
+/// value has never changed. Every checkpoint the search visits is extended.
+pub fn read_checkpoint<F>(
+    env: &Env,
+    count_key: &DataKey,
+    key: F,
+    ledger: u32,
+) -> Result<Option<i128>, TokenError>
+where
+    F: Fn(u32) -> DataKey,
+{
+    let count = checkpoint_count(env, count_key);
+    if count == 0 {
+        return Ok(None);
+    }
+    extend_persistent(env, count_key);
+
+    let (mut low, mut high) = (0u32, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let checkpoint = load_checkpoint(env, &key(mid))?;
+        if checkpoint.ledger <= ledger {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 0 {
+        return Ok(Some(0));
+    }
+    let checkpoint = load_checkpoint(env, &key(low - 1))?;
+    Ok(Some(checkpoint.amount))
 }
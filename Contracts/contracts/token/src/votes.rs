@@ -0,0 +1,132 @@
+use crate::storage::{self, Checkpoint, DataKey};
+use crate::{checked, TokenError};
+use soroban_sdk::{Address, Env, Symbol};
+
+// Accounts delegate to themselves until they choose otherwise, so holding
+// tokens is enough to have voting power.
+
+pub fn delegate_of(env: &Env, id: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegate(id.clone()))
+        .unwrap_or_else(|| id.clone())
+}
+
+pub fn set_delegate(env: &Env, delegator: &Address, delegatee: &Address) -> Result<(), TokenError> {
+    let previous = delegate_of(env, delegator);
+    if previous == *delegatee {
+        return Ok(());
+    }
+
+    // Pin the delegator's own power before it stops following its balance;
+    // an account without checkpoints would otherwise keep voting with the
+    // balance it just delegated away
+    let count_key = DataKey::VotingPowerCheckpointCount(delegator.clone());
+    if storage::checkpoint_count(env, &count_key) == 0 {
+        let power = storage::balance_of(env, delegator);
+        storage::write_checkpoint(
+            env,
+            &count_key,
+            |index| DataKey::VotingPowerCheckpoint(delegator.clone(), index),
+            power,
+            power,
+        )?;
+    }
+
+    let key = DataKey::Delegate(delegator.clone());
+    if delegator == delegatee {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, delegatee);
+        storage::extend_persistent(env, &key);
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "delegate_changed"), delegator.clone()),
+        (previous.clone(), delegatee.clone()),
+    );
+
+    let balance = storage::balance_of(env, delegator);
+    move_voting_power(env, &previous, delegatee, balance)
+}
+
+pub fn voting_power(env: &Env, id: &Address) -> i128 {
+    let count_key = DataKey::VotingPowerCheckpointCount(id.clone());
+    match storage::checkpoint_count(env, &count_key) {
+        0 => storage::balance_of(env, id),
+        count => {
+            let last: Checkpoint = env
+                .storage()
+                .persistent()
+                .get(&DataKey::VotingPowerCheckpoint(id.clone(), count - 1))
+                .unwrap();
+            last.amount
+        }
+    }
+}
+
+pub fn voting_power_at(env: &Env, id: &Address, ledger: u32) -> Result<i128, TokenError> {
+    let checkpointed = storage::read_checkpoint(
+        env,
+        &DataKey::VotingPowerCheckpointCount(id.clone()),
+        |index| DataKey::VotingPowerCheckpoint(id.clone(), index),
+        ledger,
+    )?;
+    match checkpointed {
+        Some(power) => Ok(power),
+        None => storage::balance_at(env, id, ledger),
+    }
+}
+
+/// Credit or debit the delegate of `holder` after its balance changed by
+/// `delta`. Must run before the new balance is stored, since an account with
+/// no checkpoints yet derives its power from its balance.
+pub fn apply_balance_change(env: &Env, holder: &Address, delta: i128) -> Result<(), TokenError> {
+    if delta == 0 {
+        return Ok(());
+    }
+    let delegatee = delegate_of(env, holder);
+    let current = voting_power(env, &delegatee);
+    let updated = checked(current.checked_add(delta))?;
+    write_voting_power(env, &delegatee, current, updated)
+}
+
+fn move_voting_power(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), TokenError> {
+    if from == to || amount == 0 {
+        return Ok(());
+    }
+
+    let from_power = voting_power(env, from);
+    let new_from = checked(from_power.checked_sub(amount))?;
+    write_voting_power(env, from, from_power, new_from)?;
+
+    let to_power = voting_power(env, to);
+    let new_to = checked(to_power.checked_add(amount))?;
+    write_voting_power(env, to, to_power, new_to)
+}
+
+fn write_voting_power(
+    env: &Env,
+    id: &Address,
+    previous: i128,
+    amount: i128,
+) -> Result<(), TokenError> {
+    storage::write_checkpoint(
+        env,
+        &DataKey::VotingPowerCheckpointCount(id.clone()),
+        |index| DataKey::VotingPowerCheckpoint(id.clone(), index),
+        previous,
+        amount,
+    )?;
+
+    env.events().publish(
+        (Symbol::new(env, "voting_power_changed"), id.clone()),
+        (previous, amount),
+    );
+    Ok(())
+}
@@ -2,7 +2,7 @@ use soroban_sdk::{
     contract, contractimpl, testutils::Address as _, testutils::Ledger as _, Address, Env, IntoVal,
     Symbol,
 };
-use token::{TokenContract, TokenContractClient};
+use token::{TokenContract, TokenContractClient, TokenError};
 
 #[contract]
 struct HookReceiver;
@@ -165,5 +165,9 @@ fn unauthorized_account_cannot_spend() {
     client.set_authorized(&owner, &false);
 
     assert!(!client.authorized(&owner));
+    assert_eq!(
+        client.try_transfer(&owner, &recipient, &10),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
     assert_eq!(client.balance(&recipient), 0);
 }
@@ -0,0 +1,116 @@
+use shared::admin_transfer::ADMIN_TRANSFER_WINDOW;
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal, Symbol,
+};
+use token::{TokenContract, TokenContractClient, TokenError};
+
+fn set_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    (client, admin)
+}
+
+#[test]
+fn proposed_admin_takes_over_on_acceptance() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+    set_timestamp(&env, 1_000);
+
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.admin(), admin);
+    let pending = client.pending_admin().unwrap();
+    assert_eq!(pending.admin, new_admin);
+    assert_eq!(pending.expires_at, 1_000 + ADMIN_TRANSFER_WINDOW);
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.admin(), new_admin);
+    assert_eq!(client.pending_admin(), None);
+
+    let set_admin = (
+        client.address.clone(),
+        (Symbol::new(&env, "set_admin"), admin).into_val(&env),
+        new_admin.into_val(&env),
+    );
+    assert!(env.events().all().contains(&set_admin));
+}
+
+#[test]
+fn expired_or_cancelled_transfer_cannot_be_accepted() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(TokenError::NoPendingAdmin))
+    );
+
+    client.propose_admin(&new_admin);
+    set_timestamp(&env, ADMIN_TRANSFER_WINDOW + 1);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(TokenError::AdminTransferExpired))
+    );
+
+    client.cancel_admin_transfer();
+    assert_eq!(client.pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(TokenError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_cancel_admin_transfer(),
+        Err(Ok(TokenError::NoPendingAdmin))
+    );
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn new_proposal_replaces_the_pending_one() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let typo = Address::generate(&env);
+    let intended = Address::generate(&env);
+
+    client.propose_admin(&typo);
+    client.propose_admin(&intended);
+    assert_eq!(client.pending_admin().unwrap().admin, intended);
+
+    client.accept_admin();
+    assert_eq!(client.admin(), intended);
+}
+
+#[test]
+fn set_admin_only_proposes_the_new_admin() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let pending = Address::generate(&env);
+    let replacement = Address::generate(&env);
+
+    client.propose_admin(&pending);
+    client.set_admin(&replacement);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // The old admin stays in charge until the replacement accepts
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_admin().unwrap().admin, replacement);
+
+    client.accept_admin();
+    assert_eq!(client.admin(), replacement);
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
-use token::{TokenContract, TokenContractClient};
+use token::{TokenContract, TokenContractClient, TokenError};
 
 #[test]
 fn mint_overflow_attack() {
@@ -19,4 +19,7 @@ fn mint_overflow_attack() {
 
     client.mint(&admin, &i128::MAX);
     assert_eq!(client.total_supply(), i128::MAX);
+
+    assert_eq!(client.try_mint(&admin, &1), Err(Ok(TokenError::Overflow)));
+    assert_eq!(client.total_supply(), i128::MAX);
 }
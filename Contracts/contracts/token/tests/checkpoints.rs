@@ -0,0 +1,175 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    xdr::{LedgerEntryData, ScVal},
+    Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+use token::{TokenContract, TokenContractClient, TokenError};
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const CHECKPOINT_TTL: u32 = 30 * DAY_IN_LEDGERS;
+
+fn set_sequence(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    (client, admin)
+}
+
+/// Storage key of `owner`'s checkpoint `index` of the given kind
+fn checkpoint_key(env: &Env, kind: &str, owner: &Address, index: u32) -> Val {
+    (Symbol::new(env, kind), owner.clone(), index).into_val(env)
+}
+
+/// Ledgers left before the contract data entry under `key` expires
+fn ttl(env: &Env, key: &Val) -> u32 {
+    let key = ScVal::try_from_val(env, key).unwrap();
+    let budget = env.host().budget_cloned();
+    let live_until = env
+        .host()
+        .with_mut_storage(|storage| {
+            Ok(storage
+                .map
+                .iter(&budget)?
+                .find_map(|(_, entry)| match entry {
+                    Some((entry, live_until)) => match &entry.data {
+                        LedgerEntryData::ContractData(data) if data.key == key => *live_until,
+                        _ => None,
+                    },
+                    None => None,
+                }))
+        })
+        .unwrap()
+        .unwrap();
+    live_until - env.ledger().sequence()
+}
+
+#[test]
+fn balance_history_follows_transfers_mints_and_burns() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    set_sequence(&env, 10);
+    client.mint(&alice, &1_000);
+
+    set_sequence(&env, 20);
+    client.transfer(&alice, &bob, &300);
+    // Several changes in one ledger collapse into one checkpoint
+    client.transfer(&alice, &bob, &100);
+
+    set_sequence(&env, 30);
+    client.burn(&bob, &50);
+
+    set_sequence(&env, 40);
+    client.clawback(&alice, &200);
+
+    set_sequence(&env, 50);
+
+    assert_eq!(client.balance_at(&alice, &9), 0);
+    assert_eq!(client.balance_at(&alice, &10), 1_000);
+    assert_eq!(client.balance_at(&alice, &19), 1_000);
+    assert_eq!(client.balance_at(&alice, &20), 600);
+    assert_eq!(client.balance_at(&alice, &40), 400);
+    assert_eq!(client.balance_at(&alice, &50), client.balance(&alice));
+
+    assert_eq!(client.balance_at(&bob, &15), 0);
+    assert_eq!(client.balance_at(&bob, &20), 400);
+    assert_eq!(client.balance_at(&bob, &30), 350);
+    assert_eq!(client.balance_at(&bob, &45), 350);
+
+    assert_eq!(client.total_supply_at(&9), 0);
+    assert_eq!(client.total_supply_at(&10), 1_000);
+    assert_eq!(client.total_supply_at(&20), 1_000);
+    assert_eq!(client.total_supply_at(&30), 950);
+    assert_eq!(client.total_supply_at(&40), 750);
+    assert_eq!(client.total_supply_at(&50), client.total_supply());
+}
+
+#[test]
+fn untouched_account_reports_zero_history() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let nobody = Address::generate(&env);
+
+    set_sequence(&env, 100);
+    assert_eq!(client.balance_at(&nobody, &1), 0);
+    assert_eq!(client.balance_at(&nobody, &100), 0);
+}
+
+#[test]
+fn future_ledger_is_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let next = env.ledger().sequence() + 1;
+
+    assert_eq!(
+        client.try_balance_at(&alice, &next),
+        Err(Ok(TokenError::FutureLedger))
+    );
+    assert_eq!(
+        client.try_total_supply_at(&next),
+        Err(Ok(TokenError::FutureLedger))
+    );
+}
+
+#[test]
+fn checkpoints_are_kept_alive_when_written_and_read() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    // A balance stored before checkpointing existed
+    env.as_contract(&client.address, || {
+        let key: Val = (Symbol::new(&env, "Balance"), alice.clone()).into_val(&env);
+        env.storage().persistent().set(&key, &1_000_i128);
+    });
+
+    // The first change seeds the history at ledger 0
+    set_sequence(&env, 20);
+    client.transfer(&alice, &bob, &300);
+    let seed = checkpoint_key(&env, "BalanceCheckpoint", &alice, 0);
+    let power_seed = checkpoint_key(&env, "VotingPowerCheckpoint", &alice, 0);
+    assert_eq!(ttl(&env, &seed), CHECKPOINT_TTL);
+    assert_eq!(ttl(&env, &power_seed), CHECKPOINT_TTL);
+
+    // Reading old history extends the checkpoints it visits
+    set_sequence(&env, 20 + 2 * DAY_IN_LEDGERS);
+    assert_eq!(ttl(&env, &seed), CHECKPOINT_TTL - 2 * DAY_IN_LEDGERS);
+    assert_eq!(client.balance_at(&alice, &10), 1_000);
+    assert_eq!(ttl(&env, &seed), CHECKPOINT_TTL);
+    assert_eq!(client.voting_power_at(&alice, &10), 1_000);
+    assert_eq!(ttl(&env, &power_seed), CHECKPOINT_TTL);
+}
+
+#[test]
+fn missing_checkpoint_is_reported() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+
+    set_sequence(&env, 10);
+    client.mint(&alice, &1_000);
+    env.as_contract(&client.address, || {
+        let key = checkpoint_key(&env, "BalanceCheckpoint", &alice, 0);
+        env.storage().persistent().remove(&key);
+    });
+
+    assert_eq!(
+        client.try_balance_at(&alice, &10),
+        Err(Ok(TokenError::CheckpointMissing))
+    );
+}
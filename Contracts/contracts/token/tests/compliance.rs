@@ -0,0 +1,180 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    Address, Env, IntoVal, Symbol,
+};
+use token::{AuthorizationMode, TokenContract, TokenContractClient, TokenError};
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    (client, admin)
+}
+
+#[test]
+fn blocklist_mode_blocks_deauthorized_recipients() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.mint(&alice, &100);
+
+    assert_eq!(client.authorization_mode(), AuthorizationMode::Blocklist);
+    client.set_authorized(&bob, &false);
+
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &10),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
+    assert_eq!(
+        client.try_mint(&bob, &10),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
+
+    client.set_authorized(&bob, &true);
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.balance(&bob), 10);
+}
+
+#[test]
+fn allowlist_mode_requires_explicit_authorization() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.set_authorization_mode(&AuthorizationMode::Allowlist);
+    assert!(!client.authorized(&alice));
+    assert_eq!(
+        client.try_mint(&alice, &100),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
+
+    client.set_authorized(&alice, &true);
+    client.mint(&alice, &100);
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &10),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
+
+    client.set_authorized(&bob, &true);
+    client.transfer(&alice, &bob, &10);
+
+    // Removing alice from the allowlist stops her sending
+    client.set_authorized(&alice, &false);
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &10),
+        Err(Ok(TokenError::BalanceDeauthorized))
+    );
+}
+
+#[test]
+fn open_mode_ignores_authorization_flags() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.mint(&alice, &100);
+    client.set_authorized(&alice, &false);
+    client.set_authorized(&bob, &false);
+
+    client.set_authorization_mode(&AuthorizationMode::Open);
+    assert!(client.authorized(&alice));
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.balance(&bob), 10);
+
+    // The flags come back into force with the mode
+    client.set_authorization_mode(&AuthorizationMode::Blocklist);
+    assert!(!client.authorized(&alice));
+}
+
+#[test]
+fn freeze_blocks_sending_receiving_and_allowance_spending() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.mint(&alice, &100);
+    client.mint(&bob, &100);
+    client.approve(&alice, &spender, &50, &100);
+    client.approve(&bob, &alice, &50, &100);
+
+    client.freeze(&alice);
+    assert!(client.is_frozen(&alice));
+
+    // Freezing overrides even open mode
+    client.set_authorization_mode(&AuthorizationMode::Open);
+
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_transfer(&bob, &alice, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_mint(&alice, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_transfer_from(&spender, &alice, &bob, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_transfer_from(&alice, &bob, &spender, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_burn(&alice, &10),
+        Err(Ok(TokenError::AccountFrozen))
+    );
+
+    // The admin can still claw back a frozen balance
+    client.clawback(&alice, &40);
+    assert_eq!(client.balance(&alice), 60);
+
+    client.unfreeze(&alice);
+    assert!(!client.is_frozen(&alice));
+    client.transfer_from(&spender, &alice, &bob, &10);
+    assert_eq!(client.balance(&bob), 110);
+}
+
+#[test]
+fn compliance_changes_emit_events() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let alice = Address::generate(&env);
+
+    client.set_authorization_mode(&AuthorizationMode::Allowlist);
+    client.freeze(&alice);
+    client.unfreeze(&alice);
+
+    let events = env.events().all();
+    let mode_set = (
+        client.address.clone(),
+        (Symbol::new(&env, "set_auth_mode"), admin.clone()).into_val(&env),
+        AuthorizationMode::Allowlist.into_val(&env),
+    );
+    let frozen = (
+        client.address.clone(),
+        (Symbol::new(&env, "freeze"), admin.clone(), alice.clone()).into_val(&env),
+        ().into_val(&env),
+    );
+    let unfrozen = (
+        client.address.clone(),
+        (Symbol::new(&env, "unfreeze"), admin, alice).into_val(&env),
+        ().into_val(&env),
+    );
+    assert!(events.contains(&mode_set));
+    assert!(events.contains(&frozen));
+    assert!(events.contains(&unfrozen));
+}
@@ -1,5 +1,11 @@
-use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, IntoVal};
-use token::{TokenContract, TokenContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, Events, Ledger as _},
+    token::{Client as TokenClient, StellarAssetClient},
+    xdr::{AccountFlags, LedgerEntryData, ScVal},
+    Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+use std::rc::Rc;
+use token::{TokenContract, TokenContractClient, TokenError};
 
 #[test]
 fn standard_conformance_transfer_and_balance() {
@@ -110,6 +116,313 @@ fn standard_conformance_expired_allowance_is_zero() {
     ledger_info.sequence_number = current + 2;
     env.ledger().set(ledger_info);
     assert_eq!(client.allowance(&owner, &spender), 0);
+    assert_eq!(
+        client.try_transfer_from(&spender, &owner, &spender, &1),
+        Err(Ok(TokenError::AllowanceError))
+    );
+}
+
+/// A SEP-41 token under test: ours, or a Stellar Asset Contract
+struct Sep41<'a> {
+    id: Address,
+    token: TokenClient<'a>,
+    admin: StellarAssetClient<'a>,
+    /// The Stellar Asset Contract appends the asset name to every event's topics
+    name_topic: bool,
+}
+
+impl<'a> Sep41<'a> {
+    fn new(env: &Env, id: Address, name_topic: bool) -> Self {
+        Sep41 {
+            token: TokenClient::new(env, &id),
+            admin: StellarAssetClient::new(env, &id),
+            id,
+            name_topic,
+        }
+    }
+}
+
+fn deploy_pair(env: &Env, admin: &Address) -> [Sep41<'static>; 2] {
+    let ours = env.register_contract(None, TokenContract);
+    TokenContractClient::new(env, &ours).initialize(
+        admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    let sac = env.register_stellar_asset_contract(admin.clone());
+    [Sep41::new(env, ours, false), Sep41::new(env, sac, true)]
+}
+
+/// Set AUTH_REVOCABLE on the Stellar Asset Contract's issuer. The test
+/// harness creates the issuer without flags, and the SAC refuses to
+/// deauthorize anyone unless it is set.
+fn make_revocable(env: &Env) {
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| {
+            let accounts: std::vec::Vec<_> = storage
+                .map
+                .iter(&budget)?
+                .filter_map(|(key, entry)| match entry {
+                    Some((entry, live_until)) => match &entry.data {
+                        LedgerEntryData::Account(_) => {
+                            Some((key.clone(), (**entry).clone(), *live_until))
+                        }
+                        _ => None,
+                    },
+                    None => None,
+                })
+                .collect();
+            for (key, mut entry, live_until) in accounts {
+                if let LedgerEntryData::Account(account) = &mut entry.data {
+                    account.flags |= AccountFlags::RevocableFlag as u32;
+                }
+                storage.put(&key, &Rc::new(entry), live_until, &budget)?;
+            }
+            Ok(())
+        })
+        .unwrap();
+}
+
+type Event = (std::vec::Vec<ScVal>, ScVal);
+type Auth = (Address, Symbol, std::vec::Vec<ScVal>);
+
+/// Events the SEP-41 interface defines
+const SEP41_EVENTS: [&str; 7] = [
+    "approve",
+    "transfer",
+    "burn",
+    "mint",
+    "clawback",
+    "set_admin",
+    "set_authorized",
+];
+
+/// SEP-41 events `token` emitted after the first `since` events, and the
+/// authorizations the last call required, independent of the token's own
+/// address and name
+fn effects(env: &Env, token: &Sep41, since: u32) -> (std::vec::Vec<Event>, std::vec::Vec<Auth>) {
+    let to_xdr = |val| ScVal::try_from_val(env, &val).unwrap();
+    let is_sep41 = |topics: &soroban_sdk::Vec<Val>| {
+        let name = Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap();
+        SEP41_EVENTS
+            .iter()
+            .any(|event| name == Symbol::new(env, event))
+    };
+
+    let events = env
+        .events()
+        .all()
+        .iter()
+        .skip(since as usize)
+        .filter(|(contract, topics, _)| *contract == token.id && is_sep41(topics))
+        .map(|(_, topics, data)| {
+            let keep = topics.len() - token.name_topic as u32;
+            (
+                topics.iter().take(keep as usize).map(to_xdr).collect(),
+                to_xdr(data),
+            )
+        })
+        .collect();
+
+    let auths = env
+        .auths()
+        .into_iter()
+        .map(|(signer, invocation)| match invocation.function {
+            AuthorizedFunction::Contract((_, function, args)) => {
+                (signer, function, args.iter().map(to_xdr).collect())
+            }
+            _ => panic!("unexpected authorization"),
+        })
+        .collect();
+
+    (events, auths)
+}
+
+/// Run `step` against both tokens and require the same result, events and
+/// authorizations from each
+fn check<T: PartialEq + std::fmt::Debug>(env: &Env, pair: &[Sep41; 2], step: impl Fn(&Sep41) -> T) {
+    let since = env.events().all().len();
+    let ours = step(&pair[0]);
+    let ours_effects = effects(env, &pair[0], since);
+
+    let since = env.events().all().len();
+    let sac = step(&pair[1]);
+    let sac_effects = effects(env, &pair[1], since);
+
+    assert_eq!(ours, sac);
+    assert_eq!(ours_effects, sac_effects);
+}
+
+#[test]
+fn matches_stellar_asset_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let pair = deploy_pair(&env, &admin);
+    let expiration = env.ledger().sequence() + 100;
+
+    check(&env, &pair, |t| t.token.decimals());
+    check(&env, &pair, |t| t.admin.admin());
+
+    check(&env, &pair, |t| t.admin.mint(&owner, &1_000));
+    check(&env, &pair, |t| t.token.transfer(&owner, &recipient, &200));
+
+    // Zero and self transfers still emit `transfer`
+    check(&env, &pair, |t| t.token.transfer(&owner, &recipient, &0));
+    check(&env, &pair, |t| t.token.transfer(&owner, &owner, &50));
+
+    check(&env, &pair, |t| {
+        t.token.approve(&owner, &spender, &300, &expiration)
+    });
+    check(&env, &pair, |t| t.token.allowance(&owner, &spender));
+    check(&env, &pair, |t| {
+        t.token.transfer_from(&spender, &owner, &recipient, &150)
+    });
+    check(&env, &pair, |t| t.token.allowance(&owner, &spender));
+    check(&env, &pair, |t| t.token.burn_from(&spender, &owner, &100));
+    check(&env, &pair, |t| t.token.burn(&recipient, &50));
+
+    check(&env, &pair, |t| {
+        (
+            t.token.balance(&owner),
+            t.token.balance(&recipient),
+            t.token.balance(&spender),
+        )
+    });
+
+    check(&env, &pair, |t| t.admin.set_authorized(&recipient, &true));
+    check(&env, &pair, |t| t.admin.authorized(&recipient));
+
+    // `set_admin` deliberately differs: ours only proposes the new admin,
+    // which must accept (see admin_transfer.rs)
+    check(&env, &pair, |t| t.admin.admin());
+}
+
+#[test]
+fn expired_allowance_matches_stellar_asset_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let pair = deploy_pair(&env, &admin);
+
+    let current = env.ledger().sequence();
+    check(&env, &pair, |t| t.admin.mint(&owner, &100));
+    check(&env, &pair, |t| {
+        t.token.approve(&owner, &spender, &80, &(current + 1))
+    });
+
+    let mut ledger_info = env.ledger().get();
+    ledger_info.sequence_number = current + 2;
+    env.ledger().set(ledger_info);
+
+    check(&env, &pair, |t| t.token.allowance(&owner, &spender));
+    check(&env, &pair, |t| {
+        t.token.try_transfer_from(&spender, &owner, &spender, &1)
+    });
+
+    // Clearing an expired allowance is allowed with any past expiration
+    check(&env, &pair, |t| {
+        t.token.approve(&owner, &spender, &0, &current)
+    });
+    check(&env, &pair, |t| t.token.allowance(&owner, &spender));
+}
+
+#[test]
+fn deauthorized_account_matches_stellar_asset_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let pair = deploy_pair(&env, &admin);
+    make_revocable(&env);
+    let expiration = env.ledger().sequence() + 100;
+
+    check(&env, &pair, |t| t.admin.mint(&owner, &100));
+    check(&env, &pair, |t| t.admin.mint(&recipient, &100));
+    check(&env, &pair, |t| {
+        t.token.approve(&owner, &spender, &50, &expiration)
+    });
+    check(&env, &pair, |t| t.admin.set_authorized(&owner, &false));
+    check(&env, &pair, |t| t.admin.authorized(&owner));
+
+    // A deauthorized account can neither send, be spent from, nor receive
+    check(&env, &pair, |t| {
+        t.token.try_transfer(&owner, &recipient, &10)
+    });
+    check(&env, &pair, |t| {
+        t.token.try_transfer_from(&spender, &owner, &recipient, &10)
+    });
+    check(&env, &pair, |t| {
+        t.token.try_transfer(&recipient, &owner, &10)
+    });
+
+    check(&env, &pair, |t| t.admin.set_authorized(&owner, &true));
+    check(&env, &pair, |t| {
+        t.token.try_transfer(&recipient, &owner, &10)
+    });
+    check(&env, &pair, |t| {
+        (t.token.balance(&owner), t.token.balance(&recipient))
+    });
+}
+
+#[test]
+fn failures_match_stellar_asset_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let pair = deploy_pair(&env, &admin);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let current = env.ledger().sequence();
+
+    check(&env, &pair, |t| t.admin.mint(&owner, &100));
+
+    check(&env, &pair, |t| {
+        t.token.try_transfer(&owner, &recipient, &-1)
+    });
+    check(&env, &pair, |t| {
+        t.token.try_transfer(&owner, &recipient, &101)
+    });
+    check(&env, &pair, |t| t.token.try_burn(&owner, &101));
+    check(&env, &pair, |t| {
+        t.token.try_transfer_from(&spender, &owner, &recipient, &1)
+    });
+    check(&env, &pair, |t| {
+        t.token.try_approve(&owner, &spender, &10, &(current - 1))
+    });
+    check(&env, &pair, |t| {
+        t.token.try_approve(
+            &owner,
+            &spender,
+            &10,
+            &(env.ledger().max_live_until_ledger() + 1),
+        )
+    });
+
+    check(&env, &pair, |t| {
+        t.token.approve(&owner, &spender, &10, &(current + 10))
+    });
+    check(&env, &pair, |t| {
+        t.token.try_burn_from(&spender, &owner, &11)
+    });
+    check(&env, &pair, |t| t.admin.try_mint(&owner, &i128::MAX));
 
-    // Avoid calling transfer_from with expired allowance since the contract panics.
+    check(&env, &pair, |t| {
+        (t.token.balance(&owner), t.token.balance(&recipient))
+    });
 }
@@ -0,0 +1,127 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal, Symbol,
+};
+use token::{TokenContract, TokenContractClient};
+
+fn set_sequence(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+fn setup(env: &Env) -> TokenContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    client
+}
+
+#[test]
+fn holders_vote_with_their_own_balance_by_default() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+
+    client.mint(&alice, &500);
+
+    assert_eq!(client.delegates(&alice), alice);
+    assert_eq!(client.voting_power(&alice), 500);
+}
+
+#[test]
+fn delegated_power_follows_balance_changes() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    set_sequence(&env, 10);
+    client.mint(&alice, &1_000);
+    client.mint(&bob, &200);
+
+    set_sequence(&env, 20);
+    client.delegate(&alice, &bob);
+    assert_eq!(client.delegates(&alice), bob);
+    assert_eq!(client.voting_power(&alice), 0);
+    assert_eq!(client.voting_power(&bob), 1_200);
+
+    set_sequence(&env, 30);
+    client.transfer(&alice, &carol, &400);
+    client.burn(&alice, &100);
+    assert_eq!(client.voting_power(&bob), 700);
+    assert_eq!(client.voting_power(&carol), 400);
+
+    set_sequence(&env, 40);
+    client.mint(&alice, &50);
+    client.clawback(&bob, &200);
+    assert_eq!(client.voting_power(&bob), 550);
+
+    // Delegating back to oneself reclaims the power
+    set_sequence(&env, 50);
+    client.delegate(&alice, &alice);
+    assert_eq!(client.voting_power(&alice), 550);
+    assert_eq!(client.voting_power(&bob), 0);
+
+    set_sequence(&env, 60);
+    assert_eq!(client.voting_power_at(&alice, &10), 1_000);
+    assert_eq!(client.voting_power_at(&alice, &20), 0);
+    assert_eq!(client.voting_power_at(&bob, &10), 200);
+    assert_eq!(client.voting_power_at(&bob, &20), 1_200);
+    assert_eq!(client.voting_power_at(&bob, &30), 700);
+    assert_eq!(client.voting_power_at(&bob, &40), 550);
+    assert_eq!(client.voting_power_at(&bob, &50), 0);
+    assert_eq!(client.voting_power_at(&carol, &25), 0);
+    assert_eq!(client.voting_power_at(&carol, &30), 400);
+}
+
+#[test]
+fn delegating_an_empty_account_does_not_double_count() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    set_sequence(&env, 10);
+    client.delegate(&alice, &bob);
+
+    set_sequence(&env, 20);
+    client.mint(&alice, &100);
+    assert_eq!(client.voting_power(&alice), 0);
+    assert_eq!(client.voting_power(&bob), 100);
+    assert_eq!(client.total_supply(), 100);
+
+    set_sequence(&env, 30);
+    assert_eq!(client.voting_power_at(&alice, &20), 0);
+    assert_eq!(client.voting_power_at(&bob, &20), 100);
+}
+
+#[test]
+fn delegation_emits_events() {
+    let env = Env::default();
+    let client = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.mint(&alice, &300);
+    client.delegate(&alice, &bob);
+
+    let events = env.events().all();
+    let delegate_changed = (
+        client.address.clone(),
+        (Symbol::new(&env, "delegate_changed"), alice.clone()).into_val(&env),
+        (alice.clone(), bob.clone()).into_val(&env),
+    );
+    let power_gained = (
+        client.address.clone(),
+        (Symbol::new(&env, "voting_power_changed"), bob.clone()).into_val(&env),
+        (0_i128, 300_i128).into_val(&env),
+    );
+    assert!(events.contains(&delegate_changed));
+    assert!(events.contains(&power_gained));
+}
@@ -0,0 +1,124 @@
+use shared::events::{ContractPausedEvent, ContractUnpausedEvent};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    Address, Env, IntoVal, Symbol, TryFromVal,
+};
+use token::{TokenContract, TokenContractClient, TokenError};
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    (client, admin)
+}
+
+#[test]
+fn pause_blocks_transfers_burns_and_mints() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let pauser = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let minter = Address::generate(&env);
+    client.set_pauser(&pauser);
+    client.add_minter(&minter, &100);
+    client.mint(&alice, &100);
+    client.approve(&alice, &bob, &50, &100);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_transfer_from(&bob, &alice, &bob, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_burn(&alice, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_burn_from(&bob, &alice, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_mint(&alice, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_minter_mint(&minter, &alice, &10),
+        Err(Ok(TokenError::ContractPaused))
+    );
+
+    // The admin can still claw back while paused
+    client.clawback(&alice, &30);
+    assert_eq!(client.balance(&alice), 70);
+
+    client.unpause();
+    assert!(!client.is_paused());
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.balance(&bob), 10);
+}
+
+#[test]
+fn only_the_pauser_can_pause() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let pauser = Address::generate(&env);
+
+    assert_eq!(client.pauser(), None);
+    assert_eq!(client.try_pause(), Err(Ok(TokenError::PauserNotSet)));
+
+    client.set_pauser(&pauser);
+    assert_eq!(client.pauser(), Some(pauser.clone()));
+
+    // Pausing asks for the pauser's authorization, not the admin's
+    client.pause();
+    assert_eq!(env.auths()[0].0, pauser);
+    client.unpause();
+    assert_eq!(env.auths()[0].0, pauser);
+
+    // Handing over the role is an admin action
+    let next = Address::generate(&env);
+    client.set_pauser(&next);
+    assert_eq!(env.auths()[0].0, admin);
+    client.pause();
+    assert_eq!(env.auths()[0].0, next);
+}
+
+#[test]
+fn pausing_emits_events() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let pauser = Address::generate(&env);
+
+    client.set_pauser(&pauser);
+    let set_pauser = (
+        client.address.clone(),
+        (Symbol::new(&env, "set_pauser"), admin).into_val(&env),
+        pauser.clone().into_val(&env),
+    );
+    assert!(env.events().all().contains(&set_pauser));
+
+    client.pause();
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "paused"),).into_val(&env));
+    let paused = ContractPausedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(paused.paused_by, pauser);
+
+    client.unpause();
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "unpause"),).into_val(&env));
+    let unpaused = ContractUnpausedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(unpaused.unpaused_by, pauser);
+}
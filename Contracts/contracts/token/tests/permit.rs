@@ -0,0 +1,168 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _},
+    Address, BytesN, Env, IntoVal, Symbol,
+};
+use token::{TokenContract, TokenContractClient, TokenError};
+
+fn setup(env: &Env) -> TokenContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    client
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn sign_permit(
+    client: &TokenContractClient,
+    key: &SigningKey,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    let digest = client.permit_digest(owner, spender, &amount, &expiration_ledger, &nonce);
+    BytesN::from_array(&client.env, &key.sign(&digest.to_array()).to_bytes())
+}
+
+#[test]
+fn relayed_permit_approves_spender() {
+    let env = Env::default();
+    let client = setup(&env);
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.mint(&owner, &1_000);
+
+    client.set_permit_key(&owner, &public_key(&env, &key));
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "set_permit_key"),
+                    (owner.clone(), public_key(&env, &key)).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert_eq!(client.permit_key(&owner), Some(public_key(&env, &key)));
+
+    assert_eq!(client.nonces(&owner), 0);
+    let signature = sign_permit(&client, &key, &owner, &spender, 300, 100, 0);
+
+    // No authorization from the owner is needed
+    env.set_auths(&[]);
+    client.permit(&owner, &spender, &300, &100, &0, &signature);
+    assert!(env.auths().is_empty());
+
+    assert_eq!(client.allowance(&owner, &spender), 300);
+    assert_eq!(client.nonces(&owner), 1);
+    let approved = (
+        client.address.clone(),
+        (Symbol::new(&env, "approve"), owner.clone(), spender.clone()).into_val(&env),
+        (300_i128, 100_u32).into_val(&env),
+    );
+    assert!(env.events().all().contains(&approved));
+
+    env.mock_all_auths();
+    client.transfer_from(&spender, &owner, &recipient, &200);
+    assert_eq!(client.balance(&recipient), 200);
+}
+
+#[test]
+fn permit_nonce_prevents_replay() {
+    let env = Env::default();
+    let client = setup(&env);
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.set_permit_key(&owner, &public_key(&env, &key));
+
+    let first = sign_permit(&client, &key, &owner, &spender, 300, 100, 0);
+    client.permit(&owner, &spender, &300, &100, &0, &first);
+    client.approve(&owner, &spender, &0, &100);
+
+    assert_eq!(
+        client.try_permit(&owner, &spender, &300, &100, &0, &first),
+        Err(Ok(TokenError::InvalidNonce))
+    );
+    assert_eq!(
+        client.try_permit(&owner, &spender, &300, &100, &5, &first),
+        Err(Ok(TokenError::InvalidNonce))
+    );
+    assert_eq!(client.allowance(&owner, &spender), 0);
+
+    let second = sign_permit(&client, &key, &owner, &spender, 50, 100, 1);
+    client.permit(&owner, &spender, &50, &100, &1, &second);
+    assert_eq!(client.allowance(&owner, &spender), 50);
+    assert_eq!(client.nonces(&owner), 2);
+}
+
+#[test]
+fn permit_requires_a_registered_key() {
+    let env = Env::default();
+    let client = setup(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let signature = BytesN::from_array(&env, &[0u8; 64]);
+
+    assert_eq!(client.permit_key(&owner), None);
+    assert_eq!(
+        client.try_permit(&owner, &spender, &300, &100, &0, &signature),
+        Err(Ok(TokenError::PermitKeyNotSet))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Crypto")]
+fn permit_signed_by_another_key_is_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.set_permit_key(
+        &owner,
+        &public_key(&env, &SigningKey::from_bytes(&[1u8; 32])),
+    );
+
+    let forger = SigningKey::from_bytes(&[2u8; 32]);
+    let signature = sign_permit(&client, &forger, &owner, &spender, 300, 100, 0);
+
+    let _ = env.as_contract(&client.address, || {
+        TokenContract::permit(env.clone(), owner, spender, 300, 100, 0, signature)
+    });
+}
+
+#[test]
+#[should_panic(expected = "Crypto")]
+fn rotating_the_permit_key_voids_outstanding_permits() {
+    let env = Env::default();
+    let client = setup(&env);
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.set_permit_key(&owner, &public_key(&env, &key));
+
+    let signature = sign_permit(&client, &key, &owner, &spender, 300, 100, 0);
+    let rotated = SigningKey::from_bytes(&[3u8; 32]);
+    client.set_permit_key(&owner, &public_key(&env, &rotated));
+
+    let _ = env.as_contract(&client.address, || {
+        TokenContract::permit(env.clone(), owner, spender, 300, 100, 0, signature)
+    });
+}
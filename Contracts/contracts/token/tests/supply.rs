@@ -0,0 +1,189 @@
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    vec, Address, Env, IntoVal, Symbol,
+};
+use token::{TokenContract, TokenContractClient, TokenError};
+
+fn setup(env: &Env) -> (TokenContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &"Stellara Token".into_val(env),
+        &"STLR".into_val(env),
+        &7,
+    );
+    (client, admin)
+}
+
+#[test]
+fn max_supply_caps_every_mint() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    assert_eq!(client.max_supply(), None);
+    client.mint(&alice, &600);
+
+    // The cap cannot be set below the current supply
+    assert_eq!(
+        client.try_set_max_supply(&Some(500)),
+        Err(Ok(TokenError::MaxSupplyExceeded))
+    );
+
+    client.set_max_supply(&Some(1_000));
+    assert_eq!(client.max_supply(), Some(1_000));
+
+    assert_eq!(
+        client.try_mint(&alice, &401),
+        Err(Ok(TokenError::MaxSupplyExceeded))
+    );
+    client.mint(&alice, &300);
+
+    client.add_minter(&minter, &500);
+    assert_eq!(
+        client.try_minter_mint(&minter, &alice, &101),
+        Err(Ok(TokenError::MaxSupplyExceeded))
+    );
+    client.minter_mint(&minter, &alice, &100);
+    assert_eq!(client.total_supply(), 1_000);
+
+    // Burning frees room under the cap
+    client.burn(&alice, &50);
+    client.mint(&alice, &50);
+
+    // Lifting the cap allows minting again
+    client.set_max_supply(&None);
+    client.mint(&alice, &1);
+    assert_eq!(client.total_supply(), 1_001);
+}
+
+#[test]
+fn max_supply_cannot_be_locked_uncapped() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert_eq!(
+        client.try_lock_max_supply(),
+        Err(Ok(TokenError::MaxSupplyNotSet))
+    );
+    assert!(!client.is_max_supply_locked());
+
+    // The cap can still be set afterwards
+    client.set_max_supply(&Some(1_000));
+    assert_eq!(client.max_supply(), Some(1_000));
+}
+
+#[test]
+fn locked_max_supply_is_immutable() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    client.set_max_supply(&Some(1_000));
+    client.lock_max_supply();
+    assert!(client.is_max_supply_locked());
+
+    assert_eq!(
+        client.try_set_max_supply(&Some(2_000)),
+        Err(Ok(TokenError::MaxSupplyLocked))
+    );
+    assert_eq!(
+        client.try_set_max_supply(&None),
+        Err(Ok(TokenError::MaxSupplyLocked))
+    );
+    assert_eq!(
+        client.try_lock_max_supply(),
+        Err(Ok(TokenError::MaxSupplyLocked))
+    );
+    assert_eq!(client.max_supply(), Some(1_000));
+}
+
+#[test]
+fn minters_mint_within_their_allowance() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let rewards = Address::generate(&env);
+    let academy = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    assert_eq!(
+        client.try_minter_mint(&rewards, &alice, &1),
+        Err(Ok(TokenError::NotMinter))
+    );
+
+    client.add_minter(&rewards, &300);
+    client.add_minter(&academy, &100);
+    assert!(client.is_minter(&rewards));
+    assert_eq!(
+        client.minters(),
+        vec![&env, rewards.clone(), academy.clone()]
+    );
+
+    client.minter_mint(&rewards, &alice, &200);
+    assert_eq!(client.minter_allowance(&rewards), 100);
+    assert_eq!(
+        client.try_minter_mint(&rewards, &alice, &101),
+        Err(Ok(TokenError::MinterAllowanceExceeded))
+    );
+
+    client.minter_mint(&academy, &alice, &100);
+    assert_eq!(client.minter_allowance(&academy), 0);
+    assert_eq!(client.balance(&alice), 300);
+    assert_eq!(client.total_supply(), 300);
+
+    // Re-adding a minter replaces its allowance
+    client.add_minter(&academy, &50);
+    assert_eq!(client.minter_allowance(&academy), 50);
+
+    client.remove_minter(&rewards);
+    assert!(!client.is_minter(&rewards));
+    assert_eq!(client.minter_allowance(&rewards), 0);
+    assert_eq!(
+        client.try_minter_mint(&rewards, &alice, &1),
+        Err(Ok(TokenError::NotMinter))
+    );
+    assert_eq!(
+        client.try_remove_minter(&rewards),
+        Err(Ok(TokenError::NotMinter))
+    );
+}
+
+#[test]
+fn minter_changes_emit_events() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let minter = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    client.add_minter(&minter, &100);
+    client.minter_mint(&minter, &alice, &40);
+    client.remove_minter(&minter);
+
+    let events = env.events().all();
+    let minter_added = (
+        client.address.clone(),
+        (
+            Symbol::new(&env, "minter_added"),
+            admin.clone(),
+            minter.clone(),
+        )
+            .into_val(&env),
+        100_i128.into_val(&env),
+    );
+    let minted = (
+        client.address.clone(),
+        (Symbol::new(&env, "mint"), minter.clone(), alice.clone()).into_val(&env),
+        40_i128.into_val(&env),
+    );
+    let minter_removed = (
+        client.address.clone(),
+        (Symbol::new(&env, "minter_removed"), admin, minter).into_val(&env),
+        ().into_val(&env),
+    );
+    assert!(events.contains(&minter_added));
+    assert!(events.contains(&minted));
+    assert!(events.contains(&minter_removed));
+}
@@ -1,13 +1,18 @@
 #![no_std]
 #![allow(clippy::too_many_arguments)]
 use shared::events::{
-    ContractPausedEvent, ContractUnpausedEvent, EventEmitter, FeeCollectedEvent, TradeExecutedEvent,
+    ContractPausedEvent, ContractUnpausedEvent, ContractUpgradedEvent, EventEmitter,
+    FeeCollectedEvent, TradeExecutedEvent,
 };
 use shared::fees::{FeeError, FeeManager};
-use shared::governance::{GovernanceManager, GovernanceRole, UpgradeProposal};
+use shared::governance::{
+    ApprovalSignature, ContractCall, GovernanceError, GovernanceManager, GovernanceRole,
+    ProposalMetadata, ProposalPage, ProposalParams, ProposalStatus, Rationale, UpgradeProposal,
+    VoteChoice, VoteTally, VERSION_KEY,
+};
 use shared::state_verification::{is_trusted, trust_add, verify_with_contract};
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env, IntoVal, Symbol,
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val,
 };
 
 /// Version of this contract implementation
@@ -111,10 +116,9 @@ impl UpgradeableTradingContract {
         env.storage().persistent().set(&stats_key, &stats);
 
         // Store contract version
-        let version_key = symbol_short!("ver");
         env.storage()
             .persistent()
-            .set(&version_key, &CONTRACT_VERSION);
+            .set(&VERSION_KEY, &CONTRACT_VERSION);
 
         Ok(())
     }
@@ -234,8 +238,7 @@ impl UpgradeableTradingContract {
 
     /// Get current contract version
     pub fn get_version(env: Env) -> u32 {
-        let version_key = symbol_short!("ver");
-        env.storage().persistent().get(&version_key).unwrap_or(0)
+        env.storage().persistent().get(&VERSION_KEY).unwrap_or(0)
     }
 
     /// Get trading statistics
@@ -317,33 +320,152 @@ impl UpgradeableTradingContract {
         Ok(())
     }
 
+    /// Trust a standalone governance contract to upgrade this contract (admin only)
+    pub fn set_governance(
+        env: Env,
+        admin: Address,
+        governance: Address,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+        GovernanceManager::require_role(&env, &admin, GovernanceRole::Admin)?;
+
+        let gov_key = symbol_short!("gov");
+        env.storage().persistent().set(&gov_key, &governance);
+
+        Ok(())
+    }
+
+    /// Install new WASM on behalf of the trusted governance contract
+    pub fn upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), GovernanceError> {
+        let gov_key = symbol_short!("gov");
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&gov_key)
+            .ok_or(GovernanceError::Unauthorized)?;
+        governance.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.storage().persistent().set(&VERSION_KEY, &new_version);
+
+        EventEmitter::contract_upgraded(
+            &env,
+            ContractUpgradedEvent {
+                upgraded_by: governance,
+                new_wasm_hash,
+                new_version,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
     /// Propose an upgrade via governance
     pub fn propose_upgrade(
         env: Env,
         admin: Address,
-        new_contract_hash: Symbol,
-        description: Symbol,
+        new_contract_hash: BytesN<32>,
+        metadata: ProposalMetadata,
         approvers: soroban_sdk::Vec<Address>,
         approval_threshold: u32,
         timelock_delay: u64,
-    ) -> Result<u64, TradeError> {
+    ) -> Result<u64, GovernanceError> {
+        let params = ProposalParams::new(Self::get_version(env.clone()) + 1);
+        Self::propose_upgrade_with_params(
+            env,
+            admin,
+            new_contract_hash,
+            metadata,
+            approvers,
+            approval_threshold,
+            timelock_delay,
+            params,
+        )
+    }
+
+    /// Propose an upgrade with explicit version, cooling-off and breaking-change metadata
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_upgrade_with_params(
+        env: Env,
+        admin: Address,
+        new_contract_hash: BytesN<32>,
+        metadata: ProposalMetadata,
+        approvers: soroban_sdk::Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+        params: ProposalParams,
+    ) -> Result<u64, GovernanceError> {
         admin.require_auth();
 
-        let proposal_result = GovernanceManager::propose_upgrade(
+        GovernanceManager::propose_upgrade(
             &env,
             admin,
             new_contract_hash,
             env.current_contract_address(),
-            description,
+            metadata,
             approval_threshold,
             approvers,
             timelock_delay,
-        );
+            params,
+        )
+    }
 
-        match proposal_result {
-            Ok(id) => Ok(id),
-            Err(_) => Err(TradeError::Unauthorized),
-        }
+    /// Propose a governance action calling `function` on `target` with `args`
+    pub fn propose_action(
+        env: Env,
+        admin: Address,
+        target: Address,
+        function: Symbol,
+        args: soroban_sdk::Vec<Val>,
+        metadata: ProposalMetadata,
+        approvers: soroban_sdk::Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::propose_action(
+            &env,
+            admin,
+            ContractCall {
+                target,
+                function,
+                args,
+            },
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+        )
+    }
+
+    /// Propose a batch of governance actions that execute atomically, in order
+    pub fn propose_batch(
+        env: Env,
+        admin: Address,
+        calls: soroban_sdk::Vec<ContractCall>,
+        metadata: ProposalMetadata,
+        approvers: soroban_sdk::Vec<Address>,
+        approval_threshold: u32,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::propose_batch(
+            &env,
+            admin,
+            calls,
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+        )
     }
 
     /// Approve an upgrade proposal
@@ -351,44 +473,137 @@ impl UpgradeableTradingContract {
         env: Env,
         proposal_id: u64,
         approver: Address,
-    ) -> Result<(), TradeError> {
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
         approver.require_auth();
 
-        GovernanceManager::approve_proposal(&env, proposal_id, approver)
-            .map_err(|_| TradeError::Unauthorized)
+        GovernanceManager::approve_proposal(&env, proposal_id, approver, rationale)
     }
 
-    /// Execute an approved upgrade proposal
+    /// Register the ed25519 key an approver signs off-chain approvals with
+    pub fn set_signing_key(
+        env: Env,
+        approver: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::set_signing_key(&env, approver, public_key)
+    }
+
+    /// Digest an approver signs to approve `proposal_id`
+    pub fn approval_digest(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> BytesN<32> {
+        GovernanceManager::approval_digest(&env, proposal_id, &approver, &rationale)
+    }
+
+    /// Nonce covered by an approver's next signed approval
+    pub fn get_signature_nonce(env: Env, approver: Address) -> u64 {
+        GovernanceManager::get_signature_nonce(&env, &approver)
+    }
+
+    /// Submit approvals signed off-chain; anyone may relay them
+    pub fn approve_with_signatures(
+        env: Env,
+        proposal_id: u64,
+        signatures: soroban_sdk::Vec<ApprovalSignature>,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::approve_with_signatures(&env, proposal_id, signatures)
+    }
+
+    /// Execute an approved upgrade proposal, replacing this contract's WASM
     pub fn execute_upgrade(
         env: Env,
         proposal_id: u64,
         executor: Address,
-    ) -> Result<(), TradeError> {
+    ) -> Result<(), GovernanceError> {
         executor.require_auth();
 
         GovernanceManager::execute_proposal(&env, proposal_id, executor)
-            .map_err(|_| TradeError::Unauthorized)
     }
 
     /// Get upgrade proposal details
-    pub fn get_upgrade_proposal(env: Env, proposal_id: u64) -> Result<UpgradeProposal, TradeError> {
-        GovernanceManager::get_proposal(&env, proposal_id).map_err(|_| TradeError::Unauthorized)
+    pub fn get_upgrade_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<UpgradeProposal, GovernanceError> {
+        GovernanceManager::get_proposal(&env, proposal_id)
+    }
+
+    /// Veto a pending or approved proposal (guardian only, final)
+    pub fn veto_upgrade(
+        env: Env,
+        proposal_id: u64,
+        guardian: Address,
+        reason: Symbol,
+    ) -> Result<(), GovernanceError> {
+        guardian.require_auth();
+
+        GovernanceManager::veto_proposal(&env, proposal_id, guardian, reason)
+    }
+
+    /// Mark a stale proposal as expired (callable by anyone)
+    pub fn expire_proposal(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        GovernanceManager::expire_proposal(&env, proposal_id)
+    }
+
+    /// List proposal summaries from ID `start`, optionally filtered by status
+    pub fn list_proposals(
+        env: Env,
+        start: u64,
+        limit: u32,
+        status_filter: Option<ProposalStatus>,
+    ) -> ProposalPage {
+        GovernanceManager::list_proposals(&env, start, limit, status_filter)
+    }
+
+    /// List summaries of the proposals targeting a contract, oldest first
+    pub fn list_proposals_by_target(
+        env: Env,
+        target_contract: Address,
+        start: u64,
+        limit: u32,
+    ) -> ProposalPage {
+        GovernanceManager::list_proposals_by_target(&env, &target_contract, start, limit)
     }
 
     /// Reject an upgrade proposal
-    pub fn reject_upgrade(env: Env, proposal_id: u64, rejector: Address) -> Result<(), TradeError> {
+    pub fn reject_upgrade(
+        env: Env,
+        proposal_id: u64,
+        rejector: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
         rejector.require_auth();
 
-        GovernanceManager::reject_proposal(&env, proposal_id, rejector)
-            .map_err(|_| TradeError::Unauthorized)
+        GovernanceManager::reject_proposal(&env, proposal_id, rejector, rationale)
+    }
+
+    /// Revoke a rejection while the proposal is still pending
+    pub fn revoke_rejection_upgrade(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        approver.require_auth();
+
+        GovernanceManager::revoke_rejection(&env, proposal_id, approver, rationale)
     }
 
     /// Cancel an upgrade proposal (admin only)
-    pub fn cancel_upgrade(env: Env, proposal_id: u64, admin: Address) -> Result<(), TradeError> {
+    pub fn cancel_upgrade(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+    ) -> Result<(), GovernanceError> {
         admin.require_auth();
 
         GovernanceManager::cancel_proposal(&env, proposal_id, admin)
-            .map_err(|_| TradeError::Unauthorized)
     }
 
     /// Halt an upgrade proposal (admin only)
@@ -397,11 +612,10 @@ impl UpgradeableTradingContract {
         proposal_id: u64,
         admin: Address,
         reason: Symbol,
-    ) -> Result<(), TradeError> {
+    ) -> Result<(), GovernanceError> {
         admin.require_auth();
 
         GovernanceManager::halt_proposal(&env, proposal_id, admin, reason)
-            .map_err(|_| TradeError::Unauthorized)
     }
 
     /// Resume a halted upgrade proposal (admin only)
@@ -410,11 +624,10 @@ impl UpgradeableTradingContract {
         proposal_id: u64,
         admin: Address,
         new_timelock_delay: u64,
-    ) -> Result<(), TradeError> {
+    ) -> Result<(), GovernanceError> {
         admin.require_auth();
 
         GovernanceManager::resume_proposal(&env, proposal_id, admin, new_timelock_delay)
-            .map_err(|_| TradeError::Unauthorized)
     }
 
     /// Revoke an approval
@@ -422,17 +635,104 @@ impl UpgradeableTradingContract {
         env: Env,
         proposal_id: u64,
         approver: Address,
-    ) -> Result<(), TradeError> {
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
         approver.require_auth();
 
-        GovernanceManager::revoke_approval(&env, proposal_id, approver)
-            .map_err(|_| TradeError::Unauthorized)
+        GovernanceManager::revoke_approval(&env, proposal_id, approver, rationale)
     }
 
     /// Get time remaining until execution is possible
-    pub fn get_time_to_execution(env: Env, proposal_id: u64) -> Result<u64, TradeError> {
+    pub fn get_time_to_execution(env: Env, proposal_id: u64) -> Result<u64, GovernanceError> {
         GovernanceManager::get_time_to_execution(&env, proposal_id)
-            .map_err(|_| TradeError::Unauthorized)
+    }
+
+    /// Grant a governance role (admin only)
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        member: Address,
+        role: GovernanceRole,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::grant_role(&env, admin, member, role)
+    }
+
+    /// Revoke a member's governance role (admin only)
+    pub fn revoke_role(env: Env, admin: Address, member: Address) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::revoke_role(&env, admin, member)
+    }
+
+    /// Get the governance role held by an address, if any
+    pub fn get_role(env: Env, member: Address) -> Option<GovernanceRole> {
+        GovernanceManager::get_role(&env, &member)
+    }
+
+    /// List members holding a governance role
+    pub fn list_members(env: Env, role: GovernanceRole) -> soroban_sdk::Vec<Address> {
+        GovernanceManager::list_members(&env, role)
+    }
+
+    /// Decide a pending proposal by token-weighted vote instead of multisig (admin only)
+    pub fn enable_token_voting(
+        env: Env,
+        proposal_id: u64,
+        admin: Address,
+        token: Address,
+        voting_period: u64,
+        quorum_bps: u32,
+        majority_bps: u32,
+    ) -> Result<(), GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::enable_token_voting(
+            &env,
+            proposal_id,
+            admin,
+            token,
+            voting_period,
+            quorum_bps,
+            majority_bps,
+        )
+    }
+
+    /// Vote on a token-weighted proposal, returning the counted weight
+    pub fn cast_vote(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        choice: VoteChoice,
+    ) -> Result<i128, GovernanceError> {
+        voter.require_auth();
+
+        GovernanceManager::cast_vote(&env, proposal_id, voter, choice)
+    }
+
+    /// Close a token vote after its voting period
+    pub fn finalize_vote(env: Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        GovernanceManager::finalize_vote(&env, proposal_id)
+    }
+
+    /// Get the running tally of a token vote
+    pub fn get_vote_tally(env: Env, proposal_id: u64) -> VoteTally {
+        GovernanceManager::get_vote_tally(&env, proposal_id)
+    }
+
+    /// Move governance state from the legacy shared maps to per-proposal keys
+    ///
+    /// Returns the number of legacy entries still to be moved; call again
+    /// until it reaches 0.
+    pub fn migrate_governance_storage(
+        env: Env,
+        admin: Address,
+        max_entries: u32,
+    ) -> Result<u32, GovernanceError> {
+        admin.require_auth();
+
+        GovernanceManager::migrate_legacy_storage(&env, admin, max_entries)
     }
 }
 
@@ -3,18 +3,26 @@
 extern crate std;
 
 use super::*;
+use ed25519_dalek::{Signer, SigningKey};
 use shared::fees::FeeError;
-use shared::governance::ProposalStatus;
+use shared::governance::{
+    ApprovalSignature, ContractCall, GovernanceError, GovernanceKey, GovernanceManager,
+    GovernanceRole, LegacyUpgradeProposal, ProposalAction, ProposalMetadata, ProposalParams,
+    ProposalStatus, VoteChoice, VotingMode, MAX_TITLE_LEN, PROPOSAL_LIFETIME, VERSION_KEY,
+};
 use soroban_sdk::{
-    testutils::Address as _, testutils::Events, testutils::Ledger as _, token, Address, Env,
-    Symbol, TryIntoVal, Vec,
+    contracterror, testutils::Address as _, testutils::Events, testutils::Ledger as _, token, vec,
+    Address, Bytes, BytesN, Env, String, Symbol, TryIntoVal, Vec,
 };
 use std::sync::Mutex;
 
 static TEST_LOCK: Mutex<()> = Mutex::new(());
 
 fn serial_lock() -> std::sync::MutexGuard<'static, ()> {
-    TEST_LOCK.lock().expect("test lock poisoned")
+    // A failing test poisons the lock; the guarded data is `()` so recover
+    TEST_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 fn setup_env() -> (Env, Address, Address, Address, Address) {
@@ -48,12 +56,109 @@ fn setup_fee_token(env: &Env) -> (Address, token::Client<'_>, token::StellarAsse
     (token_id, token_client, token_admin)
 }
 
+/// Minimal "v2" contract: a single `get_version` export returning `2u32`,
+/// plus the `contractenvmetav0` section declaring protocol 20.
+///
+/// (module
+///   (func (export "get_version") (result i64) i64.const 0x200000004))
+const TRADING_V2_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7e, 0x03,
+    0x02, 0x01, 0x00, 0x07, 0x0f, 0x01, 0x0b, 0x67, 0x65, 0x74, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x00, 0x00, 0x0a, 0x0a, 0x01, 0x08, 0x00, 0x42, 0x84, 0x80, 0x80, 0x80, 0x20, 0x0b,
+    0x00, 0x1e, 0x11, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x65, 0x6e, 0x76, 0x6d, 0x65,
+    0x74, 0x61, 0x76, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+];
+
+fn upload_v2_wasm(env: &Env) -> BytesN<32> {
+    env.deployer()
+        .upload_contract_wasm(Bytes::from_slice(env, TRADING_V2_WASM))
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+enum SettingsError {
+    FeeTooHigh = 1,
+}
+
+/// Stand-in for another Stellara contract whose settings are governed
+#[contract]
+struct FeeSettings;
+
+#[contractimpl]
+impl FeeSettings {
+    pub fn set_fee_bps(env: Env, fee_bps: u32) -> Result<(), SettingsError> {
+        if fee_bps > 10_000 {
+            return Err(SettingsError::FeeTooHigh);
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("fee_bps"), &fee_bps);
+        Ok(())
+    }
+
+    pub fn fee_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("fee_bps"))
+            .unwrap_or(0)
+    }
+
+    pub fn get_version(_env: Env) -> u32 {
+        3
+    }
+}
+
+/// Voting token stub with fixed balances regardless of snapshot ledger
+#[contract]
+struct SnapshotToken;
+
+#[contractimpl]
+impl SnapshotToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        let supply: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("supply"))
+            .unwrap_or(0);
+        let old: i128 = env.storage().instance().get(&id).unwrap_or(0);
+        env.storage().instance().set(&id, &amount);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("supply"), &(supply - old + amount));
+    }
+
+    pub fn voting_power_at(env: Env, id: Address, _ledger: u32) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn total_supply_at(env: Env, _ledger: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("supply"))
+            .unwrap_or(0)
+    }
+}
+
+fn set_sequence(env: &Env, sequence: u32) {
+    let mut ledger_info = env.ledger().get();
+    ledger_info.sequence_number = sequence;
+    env.ledger().set(ledger_info);
+}
+
 fn set_timestamp(env: &Env, timestamp: u64) {
     let mut ledger_info = env.ledger().get();
     ledger_info.timestamp = timestamp;
     env.ledger().set(ledger_info);
 }
 
+fn metadata(env: &Env, title: &str) -> ProposalMetadata {
+    ProposalMetadata {
+        title: String::from_str(env, title),
+        content_hash: BytesN::from_array(env, &[7u8; 32]),
+    }
+}
+
 #[test]
 fn test_init_and_getters() {
     let _guard = serial_lock();
@@ -236,22 +341,24 @@ fn test_upgrade_proposal_flow_and_errors() {
     approvers.push_back(approver.clone());
     init_contract(&client, &admin, approvers.clone(), &executor);
 
-    // Invalid threshold -> mapped to Unauthorized
+    let v2_hash = upload_v2_wasm(&env);
+
+    // Invalid threshold is rejected with its own error
     let invalid = client.try_propose_upgrade(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &v2_hash,
+        &metadata(&env, "Upgrade"),
         &approvers,
         &0,
         &3600,
     );
-    assert_eq!(invalid, Err(Ok(TradeError::Unauthorized)));
+    assert_eq!(invalid, Err(Ok(GovernanceError::InvalidThreshold)));
 
     // Valid proposal
     let proposal_id = client.propose_upgrade(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &v2_hash,
+        &metadata(&env, "Upgrade"),
         &approvers,
         &1,
         &3600,
@@ -263,30 +370,35 @@ fn test_upgrade_proposal_flow_and_errors() {
     // Advance time past cooling-off period
     set_timestamp(&env, 1000 + 3601);
 
-    client.approve_upgrade(&proposal_id, &approver);
-    let duplicate = client.try_approve_upgrade(&proposal_id, &approver);
-    assert_eq!(duplicate, Err(Ok(TradeError::Unauthorized)));
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    let duplicate = client.try_approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(duplicate, Err(Ok(GovernanceError::InvalidProposal)));
     let proposal = client.get_upgrade_proposal(&proposal_id);
     assert_eq!(proposal.status, ProposalStatus::Approved);
 
     // Execute too early (before timelock expires)
     let execute_err = client.try_execute_upgrade(&proposal_id, &executor);
-    assert_eq!(execute_err, Err(Ok(TradeError::Unauthorized)));
+    assert_eq!(execute_err, Err(Ok(GovernanceError::TimelockNotExpired)));
 
     // Advance time past timelock
     set_timestamp(&env, 1000 + 3601 + 3600);
     client.execute_upgrade(&proposal_id, &executor);
 
-    let proposal = client.get_upgrade_proposal(&proposal_id);
+    // The v2 code only exports `get_version`, so inspect governance state directly
+    let proposal = env.as_contract(&contract_id, || {
+        GovernanceManager::get_proposal(&env, proposal_id).unwrap()
+    });
     assert_eq!(proposal.status, ProposalStatus::Executed);
 
     // Cancelling executed proposal should fail
-    let cancel_err = client.try_cancel_upgrade(&proposal_id, &admin);
-    assert_eq!(cancel_err, Err(Ok(TradeError::Unauthorized)));
+    let cancel_err = env.as_contract(&contract_id, || {
+        GovernanceManager::cancel_proposal(&env, proposal_id, admin.clone())
+    });
+    assert_eq!(cancel_err, Err(GovernanceError::InvalidProposal));
 }
 
 #[test]
-fn test_reject_and_get_proposal_errors() {
+fn test_execute_upgrade_installs_new_wasm() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
@@ -294,147 +406,132 @@ fn test_reject_and_get_proposal_errors() {
     approvers.push_back(approver.clone());
     init_contract(&client, &admin, approvers.clone(), &executor);
 
+    assert_eq!(client.get_version(), 1);
+
     let proposal_id = client.propose_upgrade(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
         &approvers,
         &1,
         &3600,
     );
 
-    client.reject_upgrade(&proposal_id, &approver);
-    let proposal = client.get_upgrade_proposal(&proposal_id);
-    assert_eq!(proposal.status, ProposalStatus::Rejected);
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
 
-    let missing = client.try_get_upgrade_proposal(&999);
-    assert_eq!(missing, Err(Ok(TradeError::Unauthorized)));
-}
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
 
-// =============================================================================
-// Event Emission Tests
-// =============================================================================
+    assert_eq!(client.get_version(), 2);
+}
 
 #[test]
-fn test_trade_emits_events() {
+fn test_propose_upgrade_validates_wasm_hash() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
     let mut approvers = Vec::new(&env);
-    approvers.push_back(approver);
-    init_contract(&client, &admin, approvers, &executor);
-
-    let (token_id, _token_client, token_admin) = setup_fee_token(&env);
-    let trader = Address::generate(&env);
-    let fee_recipient = Address::generate(&env);
-    let pair = Symbol::new(&env, "XLMUSDC");
-
-    token_admin.mint(&trader, &1000);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
 
-    let trade_id = client.trade(
-        &trader,
-        &pair,
-        &250,
-        &10,
-        &true,
-        &token_id,
-        &100,
-        &fee_recipient,
+    // All-zero hash is rejected
+    let invalid = client.try_propose_upgrade(
+        &admin,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
     );
+    assert_eq!(invalid, Err(Ok(GovernanceError::InvalidHashFormat)));
 
-    assert_eq!(trade_id, 1);
-
-    // Verify events were emitted
-    let events = env.events().all();
-
-    // Should have at least 2 events: fee_collected and trade_executed
-    // (plus any token transfer events)
-    assert!(
-        events.len() >= 2,
-        "Expected at least 2 events, got {}",
-        events.len()
+    // The uploaded hash is stored verbatim
+    let v2_hash = upload_v2_wasm(&env);
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &v2_hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
     );
-
-    // Check for trade event topic
-    let has_trade_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("trade");
-            }
-        }
-        false
-    });
-    assert!(has_trade_event, "Trade event not found");
-
-    // Check for fee event topic
-    let has_fee_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("fee");
-            }
-        }
-        false
-    });
-    assert!(has_fee_event, "Fee event not found");
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.new_contract_hash, v2_hash);
 }
 
 #[test]
-fn test_pause_emits_event() {
+fn test_trusted_governance_contract_can_upgrade() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
-    let mut approvers = Vec::new(&env);
-    approvers.push_back(approver);
-    init_contract(&client, &admin, approvers, &executor);
-
-    client.pause(&admin);
-
-    let events = env.events().all();
-
-    // Check for paused event
-    let has_pause_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("paused");
-            }
-        }
-        false
-    });
-    assert!(has_pause_event, "Pause event not found");
+    init_contract(&client, &admin, vec![&env, approver.clone()], &executor);
+
+    // Nobody is trusted until an admin names the governance contract
+    let hash = upload_v2_wasm(&env);
+    let untrusted = client.try_upgrade(&hash, &2);
+    assert_eq!(untrusted, Err(Ok(GovernanceError::Unauthorized)));
+
+    let governance = Address::generate(&env);
+    let not_admin = client.try_set_governance(&approver, &governance);
+    assert_eq!(not_admin, Err(Ok(GovernanceError::Unauthorized)));
+    client.set_governance(&admin, &governance);
+
+    client.upgrade(&hash, &2);
+    assert_eq!(
+        env.auths()[0].0,
+        governance,
+        "upgrade must be authorized by the governance contract"
+    );
+    assert_eq!(client.get_version(), 2);
 }
 
 #[test]
-fn test_unpause_emits_event() {
+fn test_action_proposal_invokes_target_after_timelock() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
     let mut approvers = Vec::new(&env);
-    approvers.push_back(approver);
-    init_contract(&client, &admin, approvers, &executor);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
 
-    client.pause(&admin);
-    client.unpause(&admin);
+    let settings_id = env.register_contract(None, FeeSettings);
+    let settings = FeeSettingsClient::new(&env, &settings_id);
 
-    let events = env.events().all();
+    let proposal_id = client.propose_action(
+        &admin,
+        &settings_id,
+        &Symbol::new(&env, "set_fee_bps"),
+        &vec![&env, 25u32.into_val(&env)],
+        &metadata(&env, "SetFee"),
+        &approvers,
+        &1,
+        &3600,
+    );
 
-    // Check for unpause event
-    let has_unpause_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("unpause");
-            }
-        }
-        false
-    });
-    assert!(has_unpause_event, "Unpause event not found");
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.target_contract, settings_id);
+    assert!(matches!(proposal.action, ProposalAction::Call(_)));
+
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    // Timelock still applies to actions
+    let early = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(early, Err(Ok(GovernanceError::TimelockNotExpired)));
+    assert_eq!(settings.fee_bps(), 0);
+
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
+
+    assert_eq!(settings.fee_bps(), 25);
+    assert_eq!(client.get_version(), 1);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
 }
 
 #[test]
-fn test_governance_proposal_emits_events() {
+fn test_action_proposal_failed_call_is_not_executed() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
@@ -442,33 +539,53 @@ fn test_governance_proposal_emits_events() {
     approvers.push_back(approver.clone());
     init_contract(&client, &admin, approvers.clone(), &executor);
 
-    // Create proposal
-    let _proposal_id = client.propose_upgrade(
+    let settings_id = env.register_contract(None, FeeSettings);
+
+    // Self-targeted actions would re-enter governance and are rejected
+    let self_call = client.try_propose_action(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &contract_id,
+        &symbol_short!("pause"),
+        &vec![&env, admin.clone().into_val(&env)],
+        &metadata(&env, "Pause"),
         &approvers,
         &1,
         &3600,
     );
+    assert_eq!(self_call, Err(Ok(GovernanceError::InvalidContractAddress)));
 
-    let events = env.events().all();
+    let proposal_id = client.propose_action(
+        &admin,
+        &settings_id,
+        &Symbol::new(&env, "set_fee_bps"),
+        &vec![&env, 20_000u32.into_val(&env)],
+        &metadata(&env, "SetFee"),
+        &approvers,
+        &1,
+        &3600,
+    );
 
-    // Check for proposal created event
-    let has_propose_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("propose");
-            }
-        }
-        false
-    });
-    assert!(has_propose_event, "Proposal created event not found");
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    let failed = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(failed, Err(Ok(GovernanceError::ActionFailed)));
+
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+}
+
+fn fee_call(env: &Env, target: &Address, function: &str, args: Vec<Val>) -> ContractCall {
+    ContractCall {
+        target: target.clone(),
+        function: Symbol::new(env, function),
+        args,
+    }
 }
 
 #[test]
-fn test_governance_approval_emits_event() {
+fn test_batch_proposal_runs_calls_in_order() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
@@ -476,37 +593,63 @@ fn test_governance_approval_emits_event() {
     approvers.push_back(approver.clone());
     init_contract(&client, &admin, approvers.clone(), &executor);
 
-    let proposal_id = client.propose_upgrade(
+    let first_id = env.register_contract(None, FeeSettings);
+    let second_id = env.register_contract(None, FeeSettings);
+    let calls = vec![
+        &env,
+        fee_call(
+            &env,
+            &first_id,
+            "set_fee_bps",
+            vec![&env, 25u32.into_val(&env)],
+        ),
+        fee_call(
+            &env,
+            &second_id,
+            "set_fee_bps",
+            vec![&env, 40u32.into_val(&env)],
+        ),
+        fee_call(&env, &first_id, "fee_bps", Vec::new(&env)),
+    ];
+
+    let proposal_id = client.propose_batch(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &calls,
+        &metadata(&env, "SetFees"),
         &approvers,
         &1,
         &3600,
     );
 
-    // Advance time past cooling-off period
-    set_timestamp(&env, 1000 + 3601);
+    // Listed under every contract the batch touches
+    for target in [&first_id, &second_id] {
+        let page = client.list_proposals_by_target(target, &0, &10);
+        assert_eq!(page.proposals.len(), 1);
+    }
 
-    client.approve_upgrade(&proposal_id, &approver);
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
 
-    let events = env.events().all();
+    assert_eq!(FeeSettingsClient::new(&env, &first_id).fee_bps(), 25);
+    assert_eq!(FeeSettingsClient::new(&env, &second_id).fee_bps(), 40);
 
-    // Check for approval event
-    let has_approve_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("approve");
-            }
-        }
-        false
-    });
-    assert!(has_approve_event, "Approval event not found");
+    // The read runs after the first write and its result is recorded
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.action_results.len(), 3);
+    let fee: u32 = proposal
+        .action_results
+        .get(2)
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(fee, 25);
 }
 
 #[test]
-fn test_governance_execution_emits_event() {
+fn test_batch_proposal_reverts_when_any_call_fails() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
@@ -514,59 +657,1714 @@ fn test_governance_execution_emits_event() {
     approvers.push_back(approver.clone());
     init_contract(&client, &admin, approvers.clone(), &executor);
 
-    let proposal_id = client.propose_upgrade(
+    let first_id = env.register_contract(None, FeeSettings);
+    let second_id = env.register_contract(None, FeeSettings);
+
+    // Empty batches and self-targeted calls are rejected up front
+    let empty = client.try_propose_batch(
+        &admin,
+        &Vec::new(&env),
+        &metadata(&env, "SetFees"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    assert_eq!(empty, Err(Ok(GovernanceError::InvalidProposal)));
+    let self_call = client.try_propose_batch(
+        &admin,
+        &vec![
+            &env,
+            fee_call(&env, &contract_id, "get_version", Vec::new(&env)),
+        ],
+        &metadata(&env, "SetFees"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    assert_eq!(self_call, Err(Ok(GovernanceError::InvalidContractAddress)));
+
+    let calls = vec![
+        &env,
+        fee_call(
+            &env,
+            &first_id,
+            "set_fee_bps",
+            vec![&env, 25u32.into_val(&env)],
+        ),
+        fee_call(
+            &env,
+            &second_id,
+            "set_fee_bps",
+            vec![&env, 20_000u32.into_val(&env)],
+        ),
+    ];
+    let proposal_id = client.propose_batch(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &calls,
+        &metadata(&env, "SetFees"),
         &approvers,
         &1,
         &3600,
     );
 
-    // Advance time past cooling-off period
     set_timestamp(&env, 1000 + 3601);
-    client.approve_upgrade(&proposal_id, &approver);
-
-    // Advance time past timelock
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
     set_timestamp(&env, 1000 + 3601 + 3600);
-    client.execute_upgrade(&proposal_id, &executor);
+    let failed = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(failed, Err(Ok(GovernanceError::ActionFailed)));
 
-    let events = env.events().all();
+    // The successful first call was rolled back with the rest
+    assert_eq!(FeeSettingsClient::new(&env, &first_id).fee_bps(), 0);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+    assert!(proposal.action_results.is_empty());
+}
 
-    // Check for execute event
-    let has_execute_event = events.iter().any(|(_, topics, _)| {
-        if let Some(first_topic) = topics.first() {
-            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
-            if let Ok(sym) = topic_str {
-                return sym == symbol_short!("execute");
-            }
-        }
-        false
-    });
-    assert!(has_execute_event, "Execute event not found");
+fn sign_approval(
+    client: &UpgradeableTradingContractClient,
+    key: &SigningKey,
+    proposal_id: u64,
+    approver: &Address,
+    rationale: Rationale,
+) -> ApprovalSignature {
+    let digest = client.approval_digest(&proposal_id, approver, &rationale);
+    let signature = key.sign(&digest.to_array()).to_bytes();
+    ApprovalSignature {
+        approver: approver.clone(),
+        signature: BytesN::from_array(&client.env, &signature),
+        rationale,
+    }
 }
 
 #[test]
-fn test_governance_rejection_emits_event() {
+fn test_signed_approvals_are_relayed_in_one_call() {
     let _guard = serial_lock();
     let (env, admin, approver, executor, contract_id) = setup_env();
     let client = UpgradeableTradingContractClient::new(&env, &contract_id);
-    let mut approvers = Vec::new(&env);
-    approvers.push_back(approver.clone());
+    let second = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), second.clone()];
     init_contract(&client, &admin, approvers.clone(), &executor);
 
+    let first_key = SigningKey::from_bytes(&[1u8; 32]);
+    let second_key = SigningKey::from_bytes(&[2u8; 32]);
+    client.set_signing_key(
+        &approver,
+        &BytesN::from_array(&env, &first_key.verifying_key().to_bytes()),
+    );
+    client.set_signing_key(
+        &second,
+        &BytesN::from_array(&env, &second_key.verifying_key().to_bytes()),
+    );
+
     let proposal_id = client.propose_upgrade(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
         &approvers,
-        &1,
+        &2,
         &3600,
     );
 
-    client.reject_upgrade(&proposal_id, &approver);
+    // Digests are bound to the approver
+    assert_ne!(
+        client.approval_digest(&proposal_id, &approver, &Rationale::None),
+        client.approval_digest(&proposal_id, &second, &Rationale::None)
+    );
 
-    let events = env.events().all();
+    // ...and to the rationale the approver signs with
+    let rationale = BytesN::from_array(&env, &[9u8; 32]);
+    assert_ne!(
+        client.approval_digest(&proposal_id, &second, &Rationale::None),
+        client.approval_digest(&proposal_id, &second, &Rationale::Hash(rationale.clone()))
+    );
+
+    set_timestamp(&env, 1000 + 3601);
+    let signatures = vec![
+        &env,
+        sign_approval(&client, &first_key, proposal_id, &approver, Rationale::None),
+        sign_approval(
+            &client,
+            &second_key,
+            proposal_id,
+            &second,
+            Rationale::Hash(rationale.clone()),
+        ),
+    ];
+    client.approve_with_signatures(&proposal_id, &signatures);
+
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.approvals_count, 2);
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+    assert_eq!(client.get_signature_nonce(&approver), 1);
+    assert_eq!(client.get_signature_nonce(&second), 1);
+
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_signed_approval_requires_registered_key() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let approvers = vec![&env, approver.clone()];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    set_timestamp(&env, 1000 + 3601);
+
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signatures = vec![
+        &env,
+        sign_approval(&client, &key, proposal_id, &approver, Rationale::None),
+    ];
+    let result = client.try_approve_with_signatures(&proposal_id, &signatures);
+    assert_eq!(result, Err(Ok(GovernanceError::SigningKeyNotFound)));
+
+    // Only governance members can register keys
+    let outsider = Address::generate(&env);
+    let result = client.try_set_signing_key(
+        &outsider,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+    );
+    assert_eq!(result, Err(Ok(GovernanceError::Unauthorized)));
+}
+
+#[test]
+#[should_panic(expected = "Crypto")]
+fn test_replayed_signature_is_rejected() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let second = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), second];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    client.set_signing_key(
+        &approver,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &2,
+        &3600,
+    );
+    set_timestamp(&env, 1000 + 3601);
+
+    let signatures = vec![
+        &env,
+        sign_approval(&client, &key, proposal_id, &approver, Rationale::None),
+    ];
+    client.approve_with_signatures(&proposal_id, &signatures);
+    client.revoke_approval_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    // The nonce has moved on, so the old signature no longer verifies
+    let _ = env.as_contract(&contract_id, || {
+        GovernanceManager::approve_with_signatures(&env, proposal_id, signatures.clone())
+    });
+}
+
+#[test]
+fn test_reject_and_get_proposal_errors() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    client.reject_upgrade(&proposal_id, &approver, &Rationale::None);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    let missing = client.try_get_upgrade_proposal(&999);
+    assert_eq!(missing, Err(Ok(GovernanceError::ProposalNotFound)));
+}
+
+#[test]
+fn test_rejections_are_counted_per_approver() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), second.clone(), third.clone()];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    // 2 of 3 approvals, so 2 rejections make it unreachable
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &2,
+        &3600,
+    );
+    assert_eq!(
+        client
+            .get_upgrade_proposal(&proposal_id)
+            .rejection_threshold,
+        2
+    );
+    set_timestamp(&env, 1000 + 3601);
+
+    // One rejection does not block the proposal
+    client.reject_upgrade(&proposal_id, &approver, &Rationale::None);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.rejections_count, 1);
+
+    let again = client.try_reject_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(again, Err(Ok(GovernanceError::DuplicateRejection)));
+    let approve = client.try_approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(approve, Err(Ok(GovernanceError::DuplicateRejection)));
+
+    // Revoked rejections no longer count
+    client.revoke_rejection_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).rejections_count,
+        0
+    );
+    let missing = client.try_revoke_rejection_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(missing, Err(Ok(GovernanceError::RejectionNotFound)));
+
+    // Approvers cannot approve and reject at once
+    client.approve_upgrade(&proposal_id, &second, &Rationale::None);
+    let both = client.try_reject_upgrade(&proposal_id, &second, &Rationale::None);
+    assert_eq!(both, Err(Ok(GovernanceError::DuplicateApproval)));
+
+    client.reject_upgrade(&proposal_id, &approver, &Rationale::None);
+    client.reject_upgrade(&proposal_id, &third, &Rationale::None);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+    assert_eq!(proposal.rejections_count, 2);
+
+    // Reaching the threshold is final
+    let revoke = client.try_revoke_rejection_upgrade(&proposal_id, &third, &Rationale::None);
+    assert_eq!(revoke, Err(Ok(GovernanceError::InvalidProposal)));
+}
+
+#[test]
+fn test_rejection_threshold_is_configurable() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let second = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), second.clone()];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+    let hash = upload_v2_wasm(&env);
+
+    let mut params = ProposalParams::new(2);
+    params.rejection_threshold = 3;
+    let too_high = client.try_propose_upgrade_with_params(
+        &admin,
+        &hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+        &params,
+    );
+    assert_eq!(too_high, Err(Ok(GovernanceError::InvalidThreshold)));
+
+    params.rejection_threshold = 1;
+    let proposal_id = client.propose_upgrade_with_params(
+        &admin,
+        &hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+        &params,
+    );
+    client.reject_upgrade(&proposal_id, &second, &Rationale::None);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: shared::events::ProposalRejectedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.current_rejections, 1);
+    assert_eq!(event.threshold, 1);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).status,
+        ProposalStatus::Rejected
+    );
+}
+
+#[test]
+fn test_grant_revoke_and_list_roles() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers, &executor);
+
+    let newcomer = Address::generate(&env);
+    assert_eq!(client.get_role(&newcomer), None);
+
+    client.grant_role(&admin, &newcomer, &GovernanceRole::Approver);
+    assert_eq!(client.get_role(&newcomer), Some(GovernanceRole::Approver));
+
+    let approvers = client.list_members(&GovernanceRole::Approver);
+    assert_eq!(approvers.len(), 2);
+    assert!(approvers.contains(&approver));
+    assert!(approvers.contains(&newcomer));
+    assert_eq!(
+        client.list_members(&GovernanceRole::Admin),
+        Vec::from_array(&env, [admin.clone()])
+    );
+
+    client.revoke_role(&admin, &newcomer);
+    assert_eq!(client.get_role(&newcomer), None);
+    assert_eq!(client.list_members(&GovernanceRole::Approver).len(), 1);
+
+    // Revoking a member without a role fails
+    let missing = client.try_revoke_role(&admin, &newcomer);
+    assert_eq!(missing, Err(Ok(GovernanceError::RoleNotFound)));
+}
+
+#[test]
+fn test_grant_role_requires_admin() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers, &executor);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_grant_role(&approver, &outsider, &GovernanceRole::Admin);
+    assert_eq!(result, Err(Ok(GovernanceError::Unauthorized)));
+}
+
+#[test]
+fn test_role_management_keeps_an_admin() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers, &executor);
+
+    let outsider = Address::generate(&env);
+
+    // The sole admin can be neither revoked nor demoted
+    assert_eq!(
+        client.try_revoke_role(&admin, &admin),
+        Err(Ok(GovernanceError::LastAdmin))
+    );
+    assert_eq!(
+        client.try_grant_role(&admin, &admin, &GovernanceRole::Approver),
+        Err(Ok(GovernanceError::LastAdmin))
+    );
+
+    // Once a second admin exists, the first may step down
+    client.grant_role(&admin, &outsider, &GovernanceRole::Admin);
+    client.revoke_role(&outsider, &admin);
+    assert_eq!(client.get_role(&admin), None);
+}
+
+#[test]
+fn test_unknown_address_has_no_role() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    set_timestamp(&env, 1000 + 3601 + 3600);
+
+    client.execute_upgrade(&proposal_id, &executor);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_unknown_address_cannot_execute() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    // Previously any unknown address defaulted to Executor
+    let stranger = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GovernanceManager::require_role(&env, &stranger, GovernanceRole::Executor),
+            Err(GovernanceError::Unauthorized)
+        );
+    });
+}
+
+#[test]
+fn test_role_changes_emit_events() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let member = Address::generate(&env);
+    let has_topic = |topic: Symbol| {
+        env.events().all().iter().any(|(_, topics, _)| {
+            topics
+                .first()
+                .and_then(|t| TryIntoVal::<Env, Symbol>::try_into_val(&t, &env).ok())
+                == Some(topic.clone())
+        })
+    };
+
+    client.grant_role(&admin, &member, &GovernanceRole::Executor);
+    assert!(has_topic(Symbol::new(&env, "role_grnt")));
+
+    client.revoke_role(&admin, &member);
+    assert!(has_topic(Symbol::new(&env, "role_rvk")));
+}
+
+fn setup_token_vote(
+    env: &Env,
+    client: &UpgradeableTradingContractClient,
+    admin: &Address,
+    approvers: &Vec<Address>,
+    holders: &[(Address, i128)],
+) -> u64 {
+    let token_id = env.register_contract(None, SnapshotToken);
+    let token = SnapshotTokenClient::new(env, &token_id);
+    for (holder, amount) in holders {
+        token.set_balance(holder, amount);
+    }
+
+    let proposal_id = client.propose_upgrade(
+        admin,
+        &upload_v2_wasm(env),
+        &metadata(env, "Upgrade"),
+        approvers,
+        &1,
+        &3600,
+    );
+
+    // 3 day vote, 40% quorum, simple majority
+    client.enable_token_voting(&proposal_id, admin, &token_id, &259_200, &4_000, &5_000);
+    set_sequence(env, env.ledger().sequence() + 1);
+    proposal_id
+}
+
+#[test]
+fn test_token_vote_passes_and_executes() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let (alice, bob, carol) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let proposal_id = setup_token_vote(
+        &env,
+        &client,
+        &admin,
+        &approvers,
+        &[
+            (alice.clone(), 600),
+            (bob.clone(), 300),
+            (carol.clone(), 100),
+        ],
+    );
+
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert!(matches!(proposal.voting_mode, VotingMode::TokenWeighted(_)));
+
+    // Approvers no longer decide this proposal
+    set_timestamp(&env, 1000 + 3601);
+    let approval = client.try_approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(approval, Err(Ok(GovernanceError::WrongVotingMode)));
+
+    assert_eq!(
+        client.cast_vote(&proposal_id, &alice, &VoteChoice::For),
+        600
+    );
+    assert_eq!(
+        client.cast_vote(&proposal_id, &bob, &VoteChoice::Against),
+        300
+    );
+    assert_eq!(
+        client.cast_vote(&proposal_id, &carol, &VoteChoice::Abstain),
+        100
+    );
+
+    let duplicate = client.try_cast_vote(&proposal_id, &alice, &VoteChoice::For);
+    assert_eq!(duplicate, Err(Ok(GovernanceError::AlreadyVoted)));
+
+    let tally = client.get_vote_tally(&proposal_id);
+    assert_eq!(
+        (tally.for_votes, tally.against_votes, tally.abstain_votes),
+        (600, 300, 100)
+    );
+
+    // Cannot close before the voting period ends
+    let early = client.try_finalize_vote(&proposal_id);
+    assert_eq!(early, Err(Ok(GovernanceError::VotingNotEnded)));
+
+    let vote_end = 1000 + 259_200;
+    set_timestamp(&env, vote_end);
+    assert_eq!(client.finalize_vote(&proposal_id), ProposalStatus::Approved);
+
+    // Timelock runs from the end of the vote
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.execution_time, vote_end + 3600);
+
+    set_timestamp(&env, vote_end + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_token_vote_fails_without_quorum_or_majority() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let (alice, bob, whale) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let holders = [(alice.clone(), 200), (bob.clone(), 100), (whale, 700)];
+
+    // 30% turnout misses the 40% quorum
+    let no_quorum = setup_token_vote(&env, &client, &admin, &approvers, &holders);
+    client.cast_vote(&no_quorum, &alice, &VoteChoice::For);
+    client.cast_vote(&no_quorum, &bob, &VoteChoice::Against);
+
+    // A tie is not a majority
+    let tied = setup_token_vote(
+        &env,
+        &client,
+        &admin,
+        &approvers,
+        &[(alice.clone(), 500), (bob.clone(), 500)],
+    );
+    client.cast_vote(&tied, &alice, &VoteChoice::For);
+    client.cast_vote(&tied, &bob, &VoteChoice::Against);
+
+    // Holders without a snapshot balance cannot vote
+    let nobody = Address::generate(&env);
+    let powerless = client.try_cast_vote(&tied, &nobody, &VoteChoice::For);
+    assert_eq!(powerless, Err(Ok(GovernanceError::NoVotingPower)));
+
+    set_timestamp(&env, 1000 + 259_200);
+    assert_eq!(client.finalize_vote(&no_quorum), ProposalStatus::Rejected);
+    assert_eq!(client.finalize_vote(&tied), ProposalStatus::Rejected);
+
+    // Voting is closed once the period has ended
+    let late = client.try_cast_vote(&no_quorum, &alice, &VoteChoice::For);
+    assert_eq!(late, Err(Ok(GovernanceError::VotingClosed)));
+}
+
+#[test]
+fn test_token_vote_overflow_is_an_error() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    let half = i128::MAX / 2;
+    let proposal_id = setup_token_vote(
+        &env,
+        &client,
+        &admin,
+        &approvers,
+        &[(alice.clone(), half), (bob.clone(), half)],
+    );
+    client.cast_vote(&proposal_id, &alice, &VoteChoice::For);
+    client.cast_vote(&proposal_id, &bob, &VoteChoice::For);
+
+    // The tally fits in an i128, but scaling it to basis points does not
+    set_timestamp(&env, 1000 + 259_200);
+    let result = client.try_finalize_vote(&proposal_id);
+    assert_eq!(result, Err(Ok(GovernanceError::VoteOverflow)));
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).status,
+        ProposalStatus::Pending
+    );
+}
+
+#[test]
+fn test_token_vote_config_is_validated() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let token_id = env.register_contract(None, SnapshotToken);
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    // Including periods that overflow the voting end or its finalization window
+    for (period, quorum, majority) in [
+        (0, 4_000, 5_000),
+        (100, 10_001, 5_000),
+        (100, 0, 10_000),
+        (u64::MAX, 4_000, 5_000),
+        (u64::MAX - 1_000, 4_000, 5_000),
+    ] {
+        let result = client.try_enable_token_voting(
+            &proposal_id,
+            &admin,
+            &token_id,
+            &period,
+            &quorum,
+            &majority,
+        );
+        assert_eq!(result, Err(Ok(GovernanceError::InvalidVotingConfig)));
+    }
+
+    // Multisig proposals do not accept token votes
+    let voter = Address::generate(&env);
+    set_sequence(&env, env.ledger().sequence() + 1);
+    let vote = client.try_cast_vote(&proposal_id, &voter, &VoteChoice::For);
+    assert_eq!(vote, Err(Ok(GovernanceError::WrongVotingMode)));
+
+    // Once approvals have started the mode is fixed
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    let late = client.try_enable_token_voting(&proposal_id, &admin, &token_id, &100, &0, &5_000);
+    assert_eq!(late, Err(Ok(GovernanceError::InvalidProposal)));
+}
+
+#[test]
+fn test_guardian_veto_is_final() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &guardian, &GovernanceRole::Guardian);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    // Veto during the timelock
+    client.veto_upgrade(&proposal_id, &guardian, &symbol_short!("backdoor"));
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).status,
+        ProposalStatus::Vetoed
+    );
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, symbol_short!("veto"));
+    let event: shared::events::ProposalVetoedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.guardian, guardian);
+    assert_eq!(event.reason, symbol_short!("backdoor"));
+    assert_eq!(event.previous_status, ProposalStatus::Approved);
+
+    // Nothing brings it back
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    let execute = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(execute, Err(Ok(GovernanceError::ProposalNotApproved)));
+    let halt = client.try_halt_upgrade(&proposal_id, &admin, &symbol_short!("undo"));
+    assert_eq!(halt, Err(Ok(GovernanceError::InvalidProposal)));
+    let cancel = client.try_cancel_upgrade(&proposal_id, &admin);
+    assert_eq!(cancel, Err(Ok(GovernanceError::InvalidProposal)));
+    let again = client.try_veto_upgrade(&proposal_id, &guardian, &symbol_short!("again"));
+    assert_eq!(again, Err(Ok(GovernanceError::InvalidProposal)));
+    assert_eq!(client.get_version(), 1);
+}
+
+#[test]
+fn test_admin_cannot_veto() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    let veto = client.try_veto_upgrade(&proposal_id, &admin, &symbol_short!("no"));
+    assert_eq!(veto, Err(Ok(GovernanceError::Unauthorized)));
+}
+
+#[test]
+fn test_guardian_cannot_propose() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &guardian, &GovernanceRole::Guardian);
+
+    let proposal = client.try_propose_upgrade(
+        &guardian,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    assert_eq!(proposal, Err(Ok(GovernanceError::Unauthorized)));
+}
+
+#[test]
+fn test_guardian_cannot_execute() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let guardian = Address::generate(&env);
+    client.grant_role(&admin, &guardian, &GovernanceRole::Guardian);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    set_timestamp(&env, 1000 + 3601 + 3600);
+
+    let execute = client.try_execute_upgrade(&proposal_id, &guardian);
+    assert_eq!(execute, Err(Ok(GovernanceError::Unauthorized)));
+    assert_eq!(client.get_version(), 1);
+}
+
+#[test]
+fn test_upgrade_records_current_and_proposed_version() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.current_version, 1);
+    assert_eq!(proposal.proposed_version, 2);
+    assert!(!proposal.breaking_change);
+
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
+
+    // The stored version follows the executed proposal
+    env.as_contract(&contract_id, || {
+        let version: u32 = env.storage().persistent().get(&VERSION_KEY).unwrap();
+        assert_eq!(version, 2);
+    });
+}
+
+#[test]
+fn test_proposal_params_are_validated() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+    let hash = upload_v2_wasm(&env);
+
+    // Version must increase
+    let same_version = client.try_propose_upgrade_with_params(
+        &admin,
+        &hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+        &ProposalParams::new(1),
+    );
+    assert_eq!(same_version, Err(Ok(GovernanceError::VersionNotIncreasing)));
+
+    // Cooling-off cannot outlast the proposal
+    let mut params = ProposalParams::new(5);
+    params.cooling_off_period = 60 * 60 * 24 * 365;
+    let endless = client.try_propose_upgrade_with_params(
+        &admin,
+        &hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+        &params,
+    );
+    assert_eq!(endless, Err(Ok(GovernanceError::InvalidProposal)));
+
+    params.cooling_off_period = 7200;
+    params.simulation_passed = true;
+    params.simulation_metadata = symbol_short!("sim_ok");
+    let proposal_id = client.propose_upgrade_with_params(
+        &admin,
+        &hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+        &params,
+    );
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.cooling_off_period, 7200);
+    assert_eq!(proposal.proposed_version, 5);
+    assert!(proposal.simulation_passed);
+    assert_eq!(proposal.simulation_metadata, symbol_short!("sim_ok"));
+}
+
+#[test]
+fn test_breaking_change_raises_threshold_and_timelock() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let approvers = vec![
+        &env,
+        approver,
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let mut params = ProposalParams::new(2);
+    params.breaking_change = true;
+    let proposal_id = client.propose_upgrade_with_params(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Breaking"),
+        &approvers,
+        &1,
+        &3600,
+        &params,
+    );
+
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert!(proposal.breaking_change);
+    assert_eq!(proposal.approval_threshold, 2);
+    assert_eq!(proposal.execution_time - proposal.created_at, 604_800);
+}
+
+#[test]
+fn test_breaking_change_needs_simulation_and_long_resume() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let approvers = vec![
+        &env,
+        approver.clone(),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let mut params = ProposalParams::new(2);
+    params.breaking_change = true;
+    let proposal_id = client.propose_upgrade_with_params(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Breaking"),
+        &approvers,
+        &2,
+        &604_800,
+        &params,
+    );
+    params.simulation_passed = true;
+    let simulated_id = client.propose_upgrade_with_params(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Simulated"),
+        &approvers,
+        &2,
+        &604_800,
+        &params,
+    );
+
+    set_timestamp(&env, 1000 + 3601);
+    for id in [proposal_id, simulated_id] {
+        client.approve_upgrade(&id, &approver, &Rationale::None);
+        client.approve_upgrade(&id, &approvers.get(1).unwrap(), &Rationale::None);
+    }
+    client.halt_upgrade(&proposal_id, &admin, &symbol_short!("audit"));
+
+    // Resuming cannot shorten the delay below the creation minimums
+    let short = client.try_resume_upgrade(&proposal_id, &admin, &60);
+    assert_eq!(short, Err(Ok(GovernanceError::TimelockTooShort)));
+    let not_breaking = client.try_resume_upgrade(&proposal_id, &admin, &3600);
+    assert_eq!(not_breaking, Err(Ok(GovernanceError::TimelockTooShort)));
+    client.resume_upgrade(&proposal_id, &admin, &604_800);
+
+    // The simulation never passed, so the timelock alone is not enough
+    set_timestamp(&env, 1000 + 3601 + 604_800);
+    let unsimulated = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(unsimulated, Err(Ok(GovernanceError::SimulationNotPassed)));
+    assert_eq!(client.get_version(), 1);
+
+    client.execute_upgrade(&simulated_id, &executor);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_current_version_is_read_from_target() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let settings_id = env.register_contract(None, FeeSettings);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GovernanceManager::current_version(&env, &settings_id),
+            Ok(3)
+        );
+        assert_eq!(
+            GovernanceManager::current_version(&env, &contract_id),
+            Ok(1)
+        );
+    });
+}
+
+#[test]
+fn test_pending_proposal_expires_after_lifetime() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    let expires_at = client.get_upgrade_proposal(&proposal_id).expires_at;
+    assert_eq!(expires_at, 1000 + 2_592_000);
+
+    // Too early to expire
+    set_timestamp(&env, expires_at - 1);
+    let early = client.try_expire_proposal(&proposal_id);
+    assert_eq!(early, Err(Ok(GovernanceError::NotExpired)));
+
+    set_timestamp(&env, expires_at);
+    let late_approval = client.try_approve_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(late_approval, Err(Ok(GovernanceError::ProposalExpired)));
+
+    client.expire_proposal(&proposal_id);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Expired);
+
+    let events = env.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(topic, symbol_short!("expire"));
+
+    // Already expired
+    let again = client.try_expire_proposal(&proposal_id);
+    assert_eq!(again, Err(Ok(GovernanceError::InvalidProposal)));
+}
+
+#[test]
+fn test_expired_proposal_cannot_be_rejected() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let second = Address::generate(&env);
+    let approvers = vec![
+        &env,
+        approver.clone(),
+        second.clone(),
+        Address::generate(&env),
+    ];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &2,
+        &3600,
+    );
+    client.reject_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    set_timestamp(&env, client.get_upgrade_proposal(&proposal_id).expires_at);
+    let late_rejection = client.try_reject_upgrade(&proposal_id, &second, &Rationale::None);
+    assert_eq!(late_rejection, Err(Ok(GovernanceError::ProposalExpired)));
+    let late_revoke =
+        client.try_revoke_rejection_upgrade(&proposal_id, &approver, &Rationale::None);
+    assert_eq!(late_revoke, Err(Ok(GovernanceError::ProposalExpired)));
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).rejections_count,
+        1
+    );
+}
+
+#[test]
+fn test_approved_proposal_expires_after_execution_window() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    let approved_at = 1000 + 3601;
+    set_timestamp(&env, approved_at);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    // Executable for 14 days once the timelock has passed
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.expires_at, approved_at + 3600 + 1_209_600);
+
+    set_timestamp(&env, proposal.expires_at);
+    let stale = client.try_execute_upgrade(&proposal_id, &executor);
+    assert_eq!(stale, Err(Ok(GovernanceError::ProposalExpired)));
+
+    client.expire_proposal(&proposal_id);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(client.get_version(), 1);
+}
+
+#[test]
+fn test_halted_proposal_keeps_its_expiry() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let wasm_hash = upload_v2_wasm(&env);
+    let resumed_id = client.propose_upgrade(
+        &admin,
+        &wasm_hash,
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    let expired_id = client.propose_upgrade(
+        &admin,
+        &wasm_hash,
+        &metadata(&env, "Other upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&resumed_id, &approver, &Rationale::None);
+    client.approve_upgrade(&expired_id, &approver, &Rationale::None);
+    client.halt_upgrade(&resumed_id, &admin, &symbol_short!("audit"));
+    client.halt_upgrade(&expired_id, &admin, &symbol_short!("audit"));
+    let expires_at = client.get_upgrade_proposal(&resumed_id).expires_at;
+
+    // Resuming after the window has closed does not reopen it
+    set_timestamp(&env, expires_at);
+    client.resume_upgrade(&resumed_id, &admin, &3600);
+    let proposal = client.get_upgrade_proposal(&resumed_id);
+    assert_eq!(proposal.status, ProposalStatus::Approved);
+    assert_eq!(proposal.expires_at, expires_at);
+
+    set_timestamp(&env, expires_at + 3600);
+    let stale = client.try_execute_upgrade(&resumed_id, &executor);
+    assert_eq!(stale, Err(Ok(GovernanceError::ProposalExpired)));
+
+    // A proposal still halted past its expiry can be marked expired
+    client.expire_proposal(&expired_id);
+    assert_eq!(
+        client.get_upgrade_proposal(&expired_id).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(client.get_version(), 1);
+}
+
+#[test]
+fn test_list_proposals_paginates_and_filters() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let hash = upload_v2_wasm(&env);
+    for _ in 0..5 {
+        client.propose_upgrade(
+            &admin,
+            &hash,
+            &metadata(&env, "Upgrade"),
+            &approvers,
+            &1,
+            &3600,
+        );
+    }
+    client.cancel_upgrade(&2, &admin);
+    client.reject_upgrade(&4, &approver, &Rationale::None);
+
+    let first_page = client.list_proposals(&0, &2, &None);
+    assert_eq!(first_page.proposals.len(), 2);
+    assert_eq!(first_page.proposals.get(0).unwrap().id, 1);
+    assert_eq!(first_page.proposals.get(1).unwrap().id, 2);
+    assert_eq!(first_page.next, Some(3));
+
+    let second_page = client.list_proposals(&3, &10, &None);
+    assert_eq!(second_page.proposals.len(), 3);
+    assert_eq!(second_page.proposals.get(0).unwrap().id, 3);
+    assert_eq!(second_page.next, None);
+
+    let pending = client.list_proposals(&1, &10, &Some(ProposalStatus::Pending));
+    assert_eq!(pending.proposals.len(), 3);
+    for summary in pending.proposals.iter() {
+        assert_eq!(summary.status, ProposalStatus::Pending);
+        assert_eq!(summary.approval_threshold, 1);
+    }
+
+    // A filtered page examines `limit` proposals even when few of them match
+    let cancelled = client.list_proposals(&3, &2, &Some(ProposalStatus::Cancelled));
+    assert_eq!(cancelled.proposals.len(), 0);
+    assert_eq!(cancelled.next, Some(5));
+    let cancelled = client.list_proposals(&1, &2, &Some(ProposalStatus::Cancelled));
+    assert_eq!(cancelled.proposals.get(0).unwrap().id, 2);
+
+    assert_eq!(client.list_proposals(&6, &10, &None).proposals.len(), 0);
+
+    // An empty page does not send clients back to the same start
+    let empty = client.list_proposals(&1, &0, &None);
+    assert_eq!(empty.proposals.len(), 0);
+    assert_eq!(empty.next, None);
+}
+
+#[test]
+fn test_list_proposals_by_target() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let settings_id = env.register_contract(None, FeeSettings);
+    client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    client.propose_action(
+        &admin,
+        &settings_id,
+        &Symbol::new(&env, "set_fee_bps"),
+        &vec![&env, 25u32.into_val(&env)],
+        &metadata(&env, "SetFee"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade2"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    let own = client.list_proposals_by_target(&contract_id, &0, &1);
+    assert_eq!(own.proposals.len(), 1);
+    assert_eq!(own.proposals.get(0).unwrap().id, 1);
+    assert_eq!(own.next, Some(1));
+    let own = client.list_proposals_by_target(&contract_id, &1, &1);
+    assert_eq!(own.proposals.get(0).unwrap().id, 3);
+    assert_eq!(own.next, None);
+    let empty = client.list_proposals_by_target(&contract_id, &0, &0);
+    assert_eq!(empty.proposals.len(), 0);
+    assert_eq!(empty.next, None);
+
+    let settings = client.list_proposals_by_target(&settings_id, &0, &10);
+    assert_eq!(settings.proposals.len(), 1);
+    assert_eq!(
+        settings.proposals.get(0).unwrap().title,
+        String::from_str(&env, "SetFee")
+    );
+
+    // Each entry is stored under its own key
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(storage.has(&GovernanceKey::TargetProposal(contract_id.clone(), 1)));
+        assert_eq!(
+            storage.get::<_, u32>(&GovernanceKey::TargetProposalCount(contract_id.clone())),
+            Some(2)
+        );
+    });
+
+    let unrelated = Address::generate(&env);
+    let empty = client.list_proposals_by_target(&unrelated, &0, &10);
+    assert_eq!(empty.proposals.len(), 0);
+    assert_eq!(empty.next, None);
+}
+
+#[test]
+fn test_migrate_legacy_proposal_maps() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let legacy = |id: u64, status: ProposalStatus, description: &str| LegacyUpgradeProposal {
+        id,
+        proposer: admin.clone(),
+        new_contract_hash: symbol_short!("v2hash"),
+        target_contract: contract_id.clone(),
+        description: Symbol::new(&env, description),
+        approval_threshold: 1,
+        approvers: approvers.clone(),
+        approvals_count: 1,
+        status,
+        created_at: 1000,
+        execution_time: 4600,
+        executed: status == ProposalStatus::Executed,
+        cooling_off_period: 3600,
+        current_version: 1,
+        proposed_version: 2,
+        simulation_passed: true,
+        simulation_metadata: symbol_short!("none"),
+        breaking_change: false,
+        halt_reason: symbol_short!(""),
+        halted_at: 0,
+    };
+
+    // Write state the way deployments before per-proposal storage did
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        let mut props: soroban_sdk::Map<u64, LegacyUpgradeProposal> = soroban_sdk::Map::new(&env);
+        props.set(1, legacy(1, ProposalStatus::Executed, "Fee_tracking"));
+        props.set(2, legacy(2, ProposalStatus::Approved, "Add_governance"));
+        let mut apprv: soroban_sdk::Map<(u64, Address), bool> = soroban_sdk::Map::new(&env);
+        apprv.set((2, approver.clone()), true);
+        let mut appr_ts: soroban_sdk::Map<(u64, Address), u64> = soroban_sdk::Map::new(&env);
+        appr_ts.set((2, approver.clone()), 4700);
+
+        storage.set(&symbol_short!("props"), &props);
+        storage.set(&symbol_short!("apprv"), &apprv);
+        storage.set(&symbol_short!("appr_ts"), &appr_ts);
+        storage.set(&symbol_short!("prop_cnt"), &2u64);
+    });
+    assert!(client.try_get_upgrade_proposal(&1).is_err());
+
+    // Four legacy entries, moved over two calls
+    assert_eq!(client.migrate_governance_storage(&admin, &3), 1);
+    assert_eq!(client.migrate_governance_storage(&admin, &3), 0);
+
+    let executed = client.get_upgrade_proposal(&1);
+    assert_eq!(executed.title, String::from_str(&env, "Fee_tracking"));
+    assert_eq!(executed.status, ProposalStatus::Executed);
+    assert!(executed.executed);
+    assert_eq!(executed.action, ProposalAction::Upgrade);
+    assert_eq!(executed.expires_at, 1000 + PROPOSAL_LIFETIME);
+
+    // A symbolic hash cannot be installed, so open proposals are cancelled
+    let open = client.get_upgrade_proposal(&2);
+    assert_eq!(open.title, String::from_str(&env, "Add_governance"));
+    assert_eq!(open.status, ProposalStatus::Cancelled);
+    assert_eq!(open.new_contract_hash, BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(open.approvals_count, 1);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&symbol_short!("props")));
+        assert!(!storage.has(&symbol_short!("apprv")));
+        assert!(!storage.has(&symbol_short!("appr_ts")));
+        assert!(storage.has(&GovernanceKey::Approval(2, approver.clone())));
+        assert_eq!(
+            storage.get::<_, u64>(&GovernanceKey::ApprovalTimestamp(2, approver.clone())),
+            Some(4700)
+        );
+    });
+    assert_eq!(
+        client
+            .list_proposals_by_target(&contract_id, &0, &10)
+            .proposals
+            .len(),
+        2
+    );
+
+    // New proposals continue the legacy numbering
+    let next = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Next"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    assert_eq!(next, 3);
+}
+
+// =============================================================================
+// Event Emission Tests
+// =============================================================================
+
+#[test]
+fn test_trade_emits_events() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    let (token_id, _token_client, token_admin) = setup_fee_token(&env);
+    let trader = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let pair = Symbol::new(&env, "XLMUSDC");
+
+    token_admin.mint(&trader, &1000);
+
+    let trade_id = client.trade(
+        &trader,
+        &pair,
+        &250,
+        &10,
+        &true,
+        &token_id,
+        &100,
+        &fee_recipient,
+    );
+
+    assert_eq!(trade_id, 1);
+
+    // Verify events were emitted
+    let events = env.events().all();
+
+    // Should have at least 2 events: fee_collected and trade_executed
+    // (plus any token transfer events)
+    assert!(
+        events.len() >= 2,
+        "Expected at least 2 events, got {}",
+        events.len()
+    );
+
+    // Check for trade event topic
+    let has_trade_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("trade");
+            }
+        }
+        false
+    });
+    assert!(has_trade_event, "Trade event not found");
+
+    // Check for fee event topic
+    let has_fee_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("fee");
+            }
+        }
+        false
+    });
+    assert!(has_fee_event, "Fee event not found");
+}
+
+#[test]
+fn test_pause_emits_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    client.pause(&admin);
+
+    let events = env.events().all();
+
+    // Check for paused event
+    let has_pause_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("paused");
+            }
+        }
+        false
+    });
+    assert!(has_pause_event, "Pause event not found");
+}
+
+#[test]
+fn test_unpause_emits_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    init_contract(&client, &admin, approvers, &executor);
+
+    client.pause(&admin);
+    client.unpause(&admin);
+
+    let events = env.events().all();
+
+    // Check for unpause event
+    let has_unpause_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("unpause");
+            }
+        }
+        false
+    });
+    assert!(has_unpause_event, "Unpause event not found");
+}
+
+#[test]
+fn test_governance_proposal_emits_events() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    // Create proposal
+    let _proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    let events = env.events().all();
+
+    // Check for proposal created event
+    let has_propose_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("propose");
+            }
+        }
+        false
+    });
+    assert!(has_propose_event, "Proposal created event not found");
+}
+
+#[test]
+fn test_governance_approval_emits_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    // Advance time past cooling-off period
+    set_timestamp(&env, 1000 + 3601);
+
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    let events = env.events().all();
+
+    // Check for approval event
+    let has_approve_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("approve");
+            }
+        }
+        false
+    });
+    assert!(has_approve_event, "Approval event not found");
+}
+
+#[test]
+fn test_governance_execution_emits_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    // Advance time past cooling-off period
+    set_timestamp(&env, 1000 + 3601);
+    client.approve_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    // Advance time past timelock
+    set_timestamp(&env, 1000 + 3601 + 3600);
+    client.execute_upgrade(&proposal_id, &executor);
+
+    let events = env.events().all();
+
+    // Check for execute event
+    let has_execute_event = events.iter().any(|(_, topics, _)| {
+        if let Some(first_topic) = topics.first() {
+            let topic_str: Result<Symbol, _> = first_topic.clone().try_into_val(&env);
+            if let Ok(sym) = topic_str {
+                return sym == symbol_short!("execute");
+            }
+        }
+        false
+    });
+    assert!(has_execute_event, "Execute event not found");
+}
+
+#[test]
+fn test_governance_rejection_emits_event() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &1,
+        &3600,
+    );
+
+    client.reject_upgrade(&proposal_id, &approver, &Rationale::None);
+
+    let events = env.events().all();
 
     // Check for reject event
     let has_reject_event = events.iter().any(|(_, topics, _)| {
@@ -581,6 +2379,103 @@ fn test_governance_rejection_emits_event() {
     assert!(has_reject_event, "Reject event not found");
 }
 
+#[test]
+fn test_proposal_metadata_is_validated_stored_and_emitted() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let approvers = vec![&env, approver.clone()];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+    let hash = upload_v2_wasm(&env);
+
+    // Titles must be present and bounded
+    let empty =
+        client.try_propose_upgrade(&admin, &hash, &metadata(&env, ""), &approvers, &1, &3600);
+    assert_eq!(empty, Err(Ok(GovernanceError::InvalidProposal)));
+    let long_title = "x".repeat(MAX_TITLE_LEN as usize + 1);
+    let long = client.try_propose_upgrade(
+        &admin,
+        &hash,
+        &metadata(&env, &long_title),
+        &approvers,
+        &1,
+        &3600,
+    );
+    assert_eq!(long, Err(Ok(GovernanceError::InvalidProposal)));
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &hash,
+        &metadata(&env, "Move fees to v2 schedule"),
+        &approvers,
+        &1,
+        &3600,
+    );
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    let title = String::from_str(&env, "Move fees to v2 schedule");
+    assert_eq!(proposal.title, title);
+    assert_eq!(proposal.content_hash, BytesN::from_array(&env, &[7u8; 32]));
+
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+            topic == symbol_short!("propose")
+        })
+        .unwrap();
+    let event: shared::events::ProposalCreatedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.title, title);
+    assert_eq!(event.content_hash, proposal.content_hash);
+}
+
+#[test]
+fn test_rationale_hashes_are_emitted() {
+    let _guard = serial_lock();
+    let (env, admin, approver, executor, contract_id) = setup_env();
+    let client = UpgradeableTradingContractClient::new(&env, &contract_id);
+    let second = Address::generate(&env);
+    let approvers = vec![&env, approver.clone(), second.clone()];
+    init_contract(&client, &admin, approvers.clone(), &executor);
+
+    let proposal_id = client.propose_upgrade(
+        &admin,
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
+        &approvers,
+        &2,
+        &3600,
+    );
+    set_timestamp(&env, 1000 + 3601);
+
+    let approve_why = BytesN::from_array(&env, &[1u8; 32]);
+    client.approve_upgrade(
+        &proposal_id,
+        &approver,
+        &Rationale::Hash(approve_why.clone()),
+    );
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: shared::events::ProposalApprovedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.rationale, Rationale::Hash(approve_why));
+
+    let revoke_why = BytesN::from_array(&env, &[2u8; 32]);
+    client.revoke_approval_upgrade(
+        &proposal_id,
+        &approver,
+        &Rationale::Hash(revoke_why.clone()),
+    );
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: shared::events::ApprovalRevokedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.rationale, Rationale::Hash(revoke_why));
+
+    let reject_why = BytesN::from_array(&env, &[3u8; 32]);
+    client.reject_upgrade(&proposal_id, &second, &Rationale::Hash(reject_why.clone()));
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: shared::events::ProposalRejectedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(event.rationale, Rationale::Hash(reject_why));
+}
+
 #[test]
 fn test_governance_cancellation_emits_event() {
     let _guard = serial_lock();
@@ -592,8 +2487,8 @@ fn test_governance_cancellation_emits_event() {
 
     let proposal_id = client.propose_upgrade(
         &admin,
-        &symbol_short!("v2hash"),
-        &symbol_short!("Upgrade"),
+        &upload_v2_wasm(&env),
+        &metadata(&env, "Upgrade"),
         &approvers,
         &1,
         &3600,
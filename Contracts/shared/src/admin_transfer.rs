@@ -0,0 +1,113 @@
+//! Two-step admin transfer shared by Stellara contracts
+//!
+//! The current admin proposes a successor, who must accept within
+//! `ADMIN_TRANSFER_WINDOW` seconds. Until then the old admin stays in charge
+//! and can cancel, so a mistyped address never takes control. Contracts keep
+//! their own admin entry and auth checks; this module only tracks the
+//! pending successor and emits the events.
+
+use crate::events::{
+    AdminTransferCancelledEvent, AdminTransferProposedEvent, AdminTransferredEvent, EventEmitter,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+/// Storage key of the pending transfer (instance storage)
+pub const PENDING_ADMIN_KEY: Symbol = symbol_short!("pend_adm");
+
+/// Seconds a proposed admin has to accept (7 days)
+pub const ADMIN_TRANSFER_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Successor awaiting acceptance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub admin: Address,
+    pub expires_at: u64,
+}
+
+/// Reasons a transfer step fails; contracts map these onto their own errors
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AdminTransferError {
+    /// No transfer is pending
+    NoPendingAdmin,
+    /// The caller is not the proposed admin
+    NotPendingAdmin,
+    /// The acceptance window has passed
+    TransferExpired,
+}
+
+pub struct AdminTransfer;
+
+impl AdminTransfer {
+    /// Propose `new_admin` as successor to `admin`, replacing any earlier
+    /// proposal
+    pub fn propose(env: &Env, admin: Address, new_admin: Address) -> PendingAdmin {
+        let pending = PendingAdmin {
+            admin: new_admin,
+            expires_at: env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW,
+        };
+        env.storage().instance().set(&PENDING_ADMIN_KEY, &pending);
+
+        EventEmitter::admin_transfer_proposed(
+            env,
+            AdminTransferProposedEvent {
+                admin,
+                pending_admin: pending.admin.clone(),
+                expires_at: pending.expires_at,
+            },
+        );
+
+        pending
+    }
+
+    /// Pending successor, if any, including one whose window has passed
+    pub fn pending(env: &Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(&PENDING_ADMIN_KEY)
+    }
+
+    /// Complete the transfer from `previous_admin` to `new_admin`
+    ///
+    /// Clears the pending entry; the caller stores `new_admin` as admin.
+    pub fn accept(
+        env: &Env,
+        previous_admin: Address,
+        new_admin: &Address,
+    ) -> Result<(), AdminTransferError> {
+        let pending = Self::pending(env).ok_or(AdminTransferError::NoPendingAdmin)?;
+        if pending.admin != *new_admin {
+            return Err(AdminTransferError::NotPendingAdmin);
+        }
+        if env.ledger().timestamp() > pending.expires_at {
+            return Err(AdminTransferError::TransferExpired);
+        }
+
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+        EventEmitter::admin_transferred(
+            env,
+            AdminTransferredEvent {
+                previous_admin,
+                new_admin: new_admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw the pending transfer
+    pub fn cancel(env: &Env, admin: Address) -> Result<(), AdminTransferError> {
+        let pending = Self::pending(env).ok_or(AdminTransferError::NoPendingAdmin)?;
+
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+        EventEmitter::admin_transfer_cancelled(
+            env,
+            AdminTransferCancelledEvent {
+                admin,
+                pending_admin: pending.admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+}
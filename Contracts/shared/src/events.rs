@@ -4,7 +4,8 @@
 //! and notification systems. All contracts should use these event types
 //! to ensure reliable backend integration.
 
-use soroban_sdk::{contracttype, Address, Symbol};
+use crate::governance::{GovernanceRole, ProposalStatus, Rationale, VoteChoice};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
 
 // =============================================================================
 // Event Topics (standardized event names)
@@ -19,17 +20,32 @@ pub mod topics {
     pub const CONTRACT_PAUSED: Symbol = symbol_short!("paused");
     pub const CONTRACT_UNPAUSED: Symbol = symbol_short!("unpause");
     pub const FEE_COLLECTED: Symbol = symbol_short!("fee");
+    pub const CONTRACT_UPGRADED: Symbol = symbol_short!("upgraded");
 
     // Governance events
     pub const PROPOSAL_CREATED: Symbol = symbol_short!("propose");
     pub const PROPOSAL_APPROVED: Symbol = symbol_short!("approve");
     pub const PROPOSAL_REJECTED: Symbol = symbol_short!("reject");
     pub const PROPOSAL_EXECUTED: Symbol = symbol_short!("execute");
+    pub const ACTION_EXECUTED: Symbol = symbol_short!("act_exec");
     pub const PROPOSAL_CANCELLED: Symbol = symbol_short!("cancel");
     pub const PROPOSAL_HALTED: Symbol = symbol_short!("halt");
     pub const PROPOSAL_RESUMED: Symbol = symbol_short!("resume");
+    pub const PROPOSAL_EXPIRED: Symbol = symbol_short!("expire");
+    pub const PROPOSAL_VETOED: Symbol = symbol_short!("veto");
     pub const APPROVAL_REVOKED: Symbol = symbol_short!("revoke");
+    pub const REJECTION_REVOKED: Symbol = symbol_short!("rej_rvk");
     pub const VALIDATION_FAILED: Symbol = symbol_short!("valfail");
+    pub const ROLE_GRANTED: Symbol = symbol_short!("role_grnt");
+    pub const ROLE_REVOKED: Symbol = symbol_short!("role_rvk");
+    pub const VOTE_CAST: Symbol = symbol_short!("vote");
+    pub const VOTE_FINALIZED: Symbol = symbol_short!("vote_end");
+    pub const SIGNING_KEY_SET: Symbol = symbol_short!("sig_key");
+
+    // Admin transfer events
+    pub const ADMIN_TRANSFER_PROPOSED: Symbol = symbol_short!("adm_prop");
+    pub const ADMIN_TRANSFERRED: Symbol = symbol_short!("adm_xfer");
+    pub const ADMIN_TRANSFER_CANCELLED: Symbol = symbol_short!("adm_cncl");
 
     // Social rewards events
     pub const REWARD_ADDED: Symbol = symbol_short!("reward");
@@ -89,6 +105,20 @@ pub struct ContractUnpausedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a contract installs new WASM at its admin's request
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContractUpgradedEvent {
+    /// Admin (usually the governance contract) that ordered the upgrade
+    pub upgraded_by: Address,
+    /// Hash of the installed WASM
+    pub new_wasm_hash: BytesN<32>,
+    /// Version recorded for the new code
+    pub new_version: u32,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a fee is collected
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -118,11 +148,13 @@ pub struct ProposalCreatedEvent {
     /// Address that created the proposal
     pub proposer: Address,
     /// Hash of the new contract to upgrade to
-    pub new_contract_hash: Symbol,
+    pub new_contract_hash: BytesN<32>,
     /// Contract being upgraded
     pub target_contract: Address,
-    /// Description of the proposal
-    pub description: Symbol,
+    /// Human-readable title
+    pub title: String,
+    /// Hash of the off-chain proposal document
+    pub content_hash: BytesN<32>,
     /// Required approvals for execution
     pub approval_threshold: u32,
     /// Timelock delay before execution (seconds)
@@ -143,6 +175,8 @@ pub struct ProposalApprovedEvent {
     pub current_approvals: u32,
     /// Required approvals for execution
     pub threshold: u32,
+    /// Hash of the approver's off-chain rationale, if given
+    pub rationale: Rationale,
     /// Block timestamp
     pub timestamp: u64,
 }
@@ -155,6 +189,12 @@ pub struct ProposalRejectedEvent {
     pub proposal_id: u64,
     /// Address that rejected
     pub rejector: Address,
+    /// Current rejection count after this rejection
+    pub current_rejections: u32,
+    /// Rejections that reject the proposal
+    pub threshold: u32,
+    /// Hash of the rejector's off-chain rationale, if given
+    pub rationale: Rationale,
     /// Block timestamp
     pub timestamp: u64,
 }
@@ -168,7 +208,23 @@ pub struct ProposalExecutedEvent {
     /// Address that executed
     pub executor: Address,
     /// New contract hash that was deployed
-    pub new_contract_hash: Symbol,
+    pub new_contract_hash: BytesN<32>,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a proposal's contract call is executed
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ActionExecutedEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Address that executed
+    pub executor: Address,
+    /// Contract that was called
+    pub target_contract: Address,
+    /// Function that was invoked
+    pub function: Symbol,
     /// Block timestamp
     pub timestamp: u64,
 }
@@ -185,6 +241,20 @@ pub struct ProposalCancelledEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a stale proposal is marked expired
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalExpiredEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Status the proposal had before expiring
+    pub previous_status: ProposalStatus,
+    /// Timestamp the proposal expired at
+    pub expires_at: u64,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a proposal is halted
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -199,6 +269,34 @@ pub struct ProposalHaltedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a guardian vetoes a proposal
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalVetoedEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Guardian who vetoed
+    pub guardian: Address,
+    /// Reason for the veto
+    pub reason: Symbol,
+    /// Status the proposal had before the veto
+    pub previous_status: ProposalStatus,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an approver registers a signing key
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SigningKeySetEvent {
+    /// Approver the key belongs to
+    pub approver: Address,
+    /// ed25519 public key
+    pub public_key: BytesN<32>,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a proposal is resumed
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -221,6 +319,22 @@ pub struct ApprovalRevokedEvent {
     pub proposal_id: u64,
     /// Approver who revoked
     pub approver: Address,
+    /// Hash of the approver's off-chain rationale, if given
+    pub rationale: Rationale,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a rejection is revoked
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RejectionRevokedEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Approver who revoked their rejection
+    pub approver: Address,
+    /// Hash of the approver's off-chain rationale, if given
+    pub rationale: Rationale,
     /// Block timestamp
     pub timestamp: u64,
 }
@@ -239,6 +353,108 @@ pub struct ValidationFailedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a governance role is granted
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RoleGrantedEvent {
+    /// Address receiving the role
+    pub member: Address,
+    /// Granted role
+    pub role: GovernanceRole,
+    /// Admin who granted the role
+    pub granted_by: Address,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a governance role is revoked
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RoleRevokedEvent {
+    /// Address losing the role
+    pub member: Address,
+    /// Revoked role
+    pub role: GovernanceRole,
+    /// Admin who revoked the role
+    pub revoked_by: Address,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a token holder votes on a proposal
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VoteCastEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Address that voted
+    pub voter: Address,
+    /// Vote choice
+    pub choice: VoteChoice,
+    /// Snapshot balance counted for the vote
+    pub weight: i128,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a token vote is closed
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VoteFinalizedEvent {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Whether quorum and majority were reached
+    pub approved: bool,
+    /// Total weight voting for
+    pub for_votes: i128,
+    /// Total weight voting against
+    pub against_votes: i128,
+    /// Total weight abstaining
+    pub abstain_votes: i128,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+// =============================================================================
+// Admin Transfer Events
+// =============================================================================
+
+/// Event emitted when an admin proposes a successor
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminTransferProposedEvent {
+    /// Current admin
+    pub admin: Address,
+    /// Proposed successor, who must accept
+    pub pending_admin: Address,
+    /// Timestamp after which the proposal can no longer be accepted
+    pub expires_at: u64,
+}
+
+/// Event emitted when a proposed admin accepts the role
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminTransferredEvent {
+    /// Admin handing over the role
+    pub previous_admin: Address,
+    /// Admin taking over the role
+    pub new_admin: Address,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an admin withdraws a pending transfer
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminTransferCancelledEvent {
+    /// Admin who cancelled
+    pub admin: Address,
+    /// Successor who was pending
+    pub pending_admin: Address,
+    /// Block timestamp
+    pub timestamp: u64,
+}
+
 // =============================================================================
 // Social Rewards Events
 // =============================================================================
@@ -297,6 +513,11 @@ impl EventEmitter {
         env.events().publish((topics::CONTRACT_PAUSED,), event);
     }
 
+    /// Emit a contract upgraded event
+    pub fn contract_upgraded(env: &Env, event: ContractUpgradedEvent) {
+        env.events().publish((topics::CONTRACT_UPGRADED,), event);
+    }
+
     /// Emit a contract unpaused event
     pub fn contract_unpaused(env: &Env, event: ContractUnpausedEvent) {
         env.events().publish((topics::CONTRACT_UNPAUSED,), event);
@@ -327,6 +548,26 @@ impl EventEmitter {
         env.events().publish((topics::PROPOSAL_EXECUTED,), event);
     }
 
+    /// Emit an action executed event
+    pub fn action_executed(env: &Env, event: ActionExecutedEvent) {
+        env.events().publish((topics::ACTION_EXECUTED,), event);
+    }
+
+    /// Emit a proposal vetoed event
+    pub fn proposal_vetoed(env: &Env, event: ProposalVetoedEvent) {
+        env.events().publish((topics::PROPOSAL_VETOED,), event);
+    }
+
+    /// Emit a signing key set event
+    pub fn signing_key_set(env: &Env, event: SigningKeySetEvent) {
+        env.events().publish((topics::SIGNING_KEY_SET,), event);
+    }
+
+    /// Emit a proposal expired event
+    pub fn proposal_expired(env: &Env, event: ProposalExpiredEvent) {
+        env.events().publish((topics::PROPOSAL_EXPIRED,), event);
+    }
+
     /// Emit a proposal cancelled event
     pub fn proposal_cancelled(env: &Env, event: ProposalCancelledEvent) {
         env.events().publish((topics::PROPOSAL_CANCELLED,), event);
@@ -347,11 +588,53 @@ impl EventEmitter {
         env.events().publish((topics::APPROVAL_REVOKED,), event);
     }
 
+    /// Emit a rejection revoked event
+    pub fn rejection_revoked(env: &Env, event: RejectionRevokedEvent) {
+        env.events().publish((topics::REJECTION_REVOKED,), event);
+    }
+
     /// Emit a validation failed event
     pub fn validation_failed(env: &Env, event: ValidationFailedEvent) {
         env.events().publish((topics::VALIDATION_FAILED,), event);
     }
 
+    /// Emit a role granted event
+    pub fn role_granted(env: &Env, event: RoleGrantedEvent) {
+        env.events().publish((topics::ROLE_GRANTED,), event);
+    }
+
+    /// Emit a role revoked event
+    pub fn role_revoked(env: &Env, event: RoleRevokedEvent) {
+        env.events().publish((topics::ROLE_REVOKED,), event);
+    }
+
+    /// Emit a vote cast event
+    pub fn vote_cast(env: &Env, event: VoteCastEvent) {
+        env.events().publish((topics::VOTE_CAST,), event);
+    }
+
+    /// Emit a vote finalized event
+    pub fn vote_finalized(env: &Env, event: VoteFinalizedEvent) {
+        env.events().publish((topics::VOTE_FINALIZED,), event);
+    }
+
+    /// Emit an admin transfer proposed event
+    pub fn admin_transfer_proposed(env: &Env, event: AdminTransferProposedEvent) {
+        env.events()
+            .publish((topics::ADMIN_TRANSFER_PROPOSED,), event);
+    }
+
+    /// Emit an admin transferred event
+    pub fn admin_transferred(env: &Env, event: AdminTransferredEvent) {
+        env.events().publish((topics::ADMIN_TRANSFERRED,), event);
+    }
+
+    /// Emit an admin transfer cancelled event
+    pub fn admin_transfer_cancelled(env: &Env, event: AdminTransferCancelledEvent) {
+        env.events()
+            .publish((topics::ADMIN_TRANSFER_CANCELLED,), event);
+    }
+
     /// Emit a reward added event
     pub fn reward_added(env: &Env, event: RewardAddedEvent) {
         env.events().publish((topics::REWARD_ADDED,), event);
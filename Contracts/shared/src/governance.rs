@@ -1,9 +1,15 @@
 use crate::events::{
-    ApprovalRevokedEvent, EventEmitter, ProposalApprovedEvent, ProposalCancelledEvent,
-    ProposalCreatedEvent, ProposalExecutedEvent, ProposalHaltedEvent, ProposalRejectedEvent,
-    ProposalResumedEvent,
+    ActionExecutedEvent, ApprovalRevokedEvent, EventEmitter, ProposalApprovedEvent,
+    ProposalCancelledEvent, ProposalCreatedEvent, ProposalExecutedEvent, ProposalExpiredEvent,
+    ProposalHaltedEvent, ProposalRejectedEvent, ProposalResumedEvent, ProposalVetoedEvent,
+    RejectionRevokedEvent, RoleGrantedEvent, RoleRevokedEvent, SigningKeySetEvent, VoteCastEvent,
+    VoteFinalizedEvent,
+};
+use crate::safe_call::safe_invoke;
+use soroban_sdk::{
+    contractclient, contracterror, contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN,
+    Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
 
 /// Upgrade proposal that must be approved via governance
 #[contracttype]
@@ -11,12 +17,15 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
 pub struct UpgradeProposal {
     pub id: u64,
     pub proposer: Address,
-    pub new_contract_hash: Symbol,
+    pub new_contract_hash: BytesN<32>, // Hash of the uploaded WASM to install
     pub target_contract: Address,
-    pub description: Symbol,
-    pub approval_threshold: u32, // e.g., 2 of 3
+    pub title: String,            // Human-readable title
+    pub content_hash: BytesN<32>, // Hash of the off-chain proposal document
+    pub approval_threshold: u32,  // e.g., 2 of 3
     pub approvers: Vec<Address>,
     pub approvals_count: u32,
+    pub rejection_threshold: u32, // Rejections that reject the proposal
+    pub rejections_count: u32,
     pub status: ProposalStatus,
     pub created_at: u64,
     pub execution_time: u64, // Timelock: when it can be executed
@@ -31,8 +40,308 @@ pub struct UpgradeProposal {
     pub breaking_change: bool,       // Whether this is a breaking change
     pub halt_reason: Symbol,         // Reason if halted (empty if not halted)
     pub halted_at: u64,              // When it was halted (0 if not halted)
+
+    pub action: ProposalAction,   // What runs on execution
+    pub voting_mode: VotingMode,  // How the proposal is decided
+    pub expires_at: u64,          // After this it can no longer be approved or executed
+    pub action_results: Vec<Val>, // Return value of each executed call, in order
+}
+
+/// Compact view of a proposal for listings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalSummary {
+    pub id: u64,
+    pub proposer: Address,
+    pub target_contract: Address,
+    pub title: String,
+    pub status: ProposalStatus,
+    pub approvals_count: u32,
+    pub approval_threshold: u32,
+    pub execution_time: u64,
+}
+
+impl From<&UpgradeProposal> for ProposalSummary {
+    fn from(proposal: &UpgradeProposal) -> Self {
+        ProposalSummary {
+            id: proposal.id,
+            proposer: proposal.proposer.clone(),
+            target_contract: proposal.target_contract.clone(),
+            title: proposal.title.clone(),
+            status: proposal.status,
+            approvals_count: proposal.approvals_count,
+            approval_threshold: proposal.approval_threshold,
+            execution_time: proposal.execution_time,
+        }
+    }
+}
+
+/// One page of proposal summaries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalPage {
+    pub proposals: Vec<ProposalSummary>,
+    pub next: Option<u64>, // `start` for the next page; None once the listing is exhausted
+}
+
+/// Proposal layout stored in the legacy `props` map
+///
+/// Frozen copy of the struct contracts were deployed with before per-proposal
+/// storage; only `StorageModule::migrate_legacy_storage` reads it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyUpgradeProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub new_contract_hash: Symbol,
+    pub target_contract: Address,
+    pub description: Symbol,
+    pub approval_threshold: u32,
+    pub approvers: Vec<Address>,
+    pub approvals_count: u32,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub execution_time: u64,
+    pub executed: bool,
+    pub cooling_off_period: u64,
+    pub current_version: u32,
+    pub proposed_version: u32,
+    pub simulation_passed: bool,
+    pub simulation_metadata: Symbol,
+    pub breaking_change: bool,
+    pub halt_reason: Symbol,
+    pub halted_at: u64,
+}
+
+impl LegacyUpgradeProposal {
+    /// Convert to the current layout
+    ///
+    /// The legacy hash was a free-form symbol, not the hash of uploaded WASM,
+    /// so it cannot be installed: proposals that were still open are
+    /// cancelled and the hash is stored as all zeroes. The description
+    /// becomes the title.
+    pub fn into_proposal(self, env: &Env) -> UpgradeProposal {
+        let status = match self.status {
+            ProposalStatus::Pending | ProposalStatus::Approved | ProposalStatus::Halted => {
+                ProposalStatus::Cancelled
+            }
+            status => status,
+        };
+
+        UpgradeProposal {
+            id: self.id,
+            proposer: self.proposer,
+            new_contract_hash: BytesN::from_array(env, &[0u8; 32]),
+            target_contract: self.target_contract,
+            title: symbol_to_string(env, &self.description),
+            content_hash: BytesN::from_array(env, &[0u8; 32]),
+            rejection_threshold: self.approvers.len().saturating_sub(self.approval_threshold) + 1,
+            approval_threshold: self.approval_threshold,
+            approvers: self.approvers,
+            approvals_count: self.approvals_count,
+            rejections_count: 0,
+            status,
+            created_at: self.created_at,
+            execution_time: self.execution_time,
+            executed: self.executed,
+            cooling_off_period: self.cooling_off_period,
+            current_version: self.current_version,
+            proposed_version: self.proposed_version,
+            simulation_passed: self.simulation_passed,
+            simulation_metadata: self.simulation_metadata,
+            breaking_change: self.breaking_change,
+            halt_reason: self.halt_reason,
+            halted_at: self.halted_at,
+            action: ProposalAction::Upgrade,
+            voting_mode: VotingMode::Multisig,
+            expires_at: self.created_at + PROPOSAL_LIFETIME,
+            action_results: Vec::new(env),
+        }
+    }
+}
+
+/// Copy a symbol's characters into a string
+fn symbol_to_string(env: &Env, symbol: &Symbol) -> String {
+    // XDR of an ScVal symbol: 4-byte type tag, 4-byte length, then the characters
+    let xdr = symbol.clone().to_xdr(env);
+    let mut header = [0u8; 8];
+    xdr.slice(0..8).copy_into_slice(&mut header);
+    let len = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+
+    let mut chars = [0u8; 32];
+    let chars = &mut chars[..len as usize];
+    xdr.slice(8..8 + len).copy_into_slice(chars);
+    String::from_bytes(env, chars)
+}
+
+/// How long a proposal can wait for its approvals (30 days)
+pub const PROPOSAL_LIFETIME: u64 = 2_592_000;
+/// How long an approved proposal stays executable after its timelock (14 days)
+pub const EXECUTION_WINDOW: u64 = 1_209_600;
+
+/// Most proposals examined by one listing call
+pub const MAX_PROPOSAL_PAGE: u32 = 50;
+
+/// What a proposal is about, shown to approvers and indexers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalMetadata {
+    pub title: String,            // At most `MAX_TITLE_LEN` bytes
+    pub content_hash: BytesN<32>, // sha256 of the audit report, changelog or spec
+}
+
+/// Longest proposal title accepted, in bytes
+pub const MAX_TITLE_LEN: u32 = 256;
+
+/// Upgrade metadata supplied by the proposer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalParams {
+    pub cooling_off_period: u64,     // Minimum time before first approval
+    pub proposed_version: u32,       // Must be above the target's current version
+    pub simulation_passed: bool,     // Whether simulation tests passed
+    pub simulation_metadata: Symbol, // Simulation results summary
+    pub breaking_change: bool,       // Raises the threshold and timelock
+    pub rejection_threshold: u32,    // 0 for the smallest blocking minority
+}
+
+impl ProposalParams {
+    /// Non-breaking change with the default cooling-off and no simulation
+    pub fn new(proposed_version: u32) -> Self {
+        ProposalParams {
+            cooling_off_period: DEFAULT_COOLING_OFF,
+            proposed_version,
+            simulation_passed: false,
+            simulation_metadata: symbol_short!("none"),
+            breaking_change: false,
+            rejection_threshold: 0,
+        }
+    }
+}
+
+/// Cooling-off applied when the proposer does not choose one (1 hour)
+pub const DEFAULT_COOLING_OFF: u64 = 3600;
+/// Minimum timelock for breaking changes (7 days)
+pub const BREAKING_CHANGE_MIN_TIMELOCK: u64 = 604_800;
+
+/// Persistent key under which a self-governed contract keeps its version
+pub const VERSION_KEY: Symbol = symbol_short!("ver");
+
+/// Optional hash of an approver's off-chain rationale
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Rationale {
+    None,
+    Hash(BytesN<32>), // sha256 of the published rationale
+}
+
+/// Off-chain approval signed with an approver's registered ed25519 key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalSignature {
+    pub approver: Address,
+    pub rationale: Rationale,  // Rationale covered by the signature
+    pub signature: BytesN<64>, // Signature over `approval_digest`
+}
+
+/// Domain tag prefixed to every signed approval digest
+pub const APPROVAL_DOMAIN: &[u8] = b"stellara.governance.approve.v1";
+
+/// Contract call carried by a governance action proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCall {
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// What an approved proposal does once its timelock passes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Install `new_contract_hash` on `target_contract`
+    Upgrade,
+    /// Invoke an arbitrary function through `safe_call::safe_invoke`
+    Call(ContractCall),
+    /// Invoke each call in order; if any fails the whole batch reverts
+    Batch(Vec<ContractCall>),
+}
+
+/// Most calls one batch proposal can carry
+pub const MAX_BATCH_ACTIONS: u32 = 10;
+
+/// How a proposal reaches its approve/reject decision
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingMode {
+    /// Fixed approver list with `approval_threshold`
+    Multisig,
+    /// Token holders vote with their balance at the snapshot ledger
+    TokenWeighted(TokenVotingConfig),
+}
+
+/// Parameters of a token-weighted vote
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenVotingConfig {
+    pub token: Address,
+    pub snapshot_ledger: u32, // Balances are read as of this ledger
+    pub voting_end: u64,      // Timestamp when voting closes
+    pub quorum_bps: u32,      // Min share of snapshot supply that must vote
+    pub majority_bps: u32,    // For-share of for+against that must be exceeded
+}
+
+/// Choice of a token-weighted voter
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VoteChoice {
+    For = 0,
+    Against = 1,
+    Abstain = 2,
+}
+
+/// Running totals of a token-weighted vote
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VoteTally {
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+}
+
+/// Historical queries a voting token must support
+#[contractclient(name = "VotingTokenClient")]
+pub trait VotingToken {
+    fn voting_power_at(env: Env, id: Address, ledger: u32) -> i128;
+    fn total_supply_at(env: Env, ledger: u32) -> i128;
+}
+
+/// Storage keys for per-proposal governance state
+///
+/// Each proposal, approval and vote lives in its own persistent entry so the
+/// cost of an operation does not grow with proposal history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceKey {
+    Proposal(u64),
+    Approval(u64, Address),
+    Rejection(u64, Address),
+    ApprovalTimestamp(u64, Address),
+    Vote(u64, Address),
+    Tally(u64),
+    TargetProposalCount(Address),
+    TargetProposal(Address, u32), // Position in the target's list -> proposal ID
+    SigningKey(Address),
+    SignatureNonce(Address),
 }
 
+/// Extend a governance entry once its TTL drops below ~7 days (5s ledgers)
+pub const GOVERNANCE_TTL_THRESHOLD: u32 = 120_960;
+/// Keep governance entries alive for ~30 days after each access
+pub const GOVERNANCE_TTL_EXTEND_TO: u32 = 518_400;
+
 /// Status of an upgrade proposal
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -44,6 +353,8 @@ pub enum ProposalStatus {
     Executed = 3,
     Cancelled = 4,
     Halted = 5, // New status for emergency halts
+    Expired = 6,
+    Vetoed = 7, // Final: blocked by a guardian
 }
 
 /// Governance role
@@ -54,9 +365,11 @@ pub enum GovernanceRole {
     Admin = 0,    // Can propose upgrades and cancel
     Approver = 1, // Can approve/reject proposals
     Executor = 2, // Can execute approved proposals (after timelock)
+    Guardian = 3, // Can only veto pending/approved proposals
 }
 
 /// Governance error codes
+#[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum GovernanceError {
@@ -87,18 +400,37 @@ pub enum GovernanceError {
     CoolingOffNotExpired = 2018,
     ApprovalNotFound = 2019,
     CannotRevokeAfterThreshold = 2020,
-}
 
-impl From<GovernanceError> for soroban_sdk::Error {
-    fn from(error: GovernanceError) -> Self {
-        soroban_sdk::Error::from_contract_error(error as u32)
-    }
-}
+    // Execution errors
+    UpgradeFailed = 2021,
+    ActionFailed = 2022,
 
-impl From<soroban_sdk::Error> for GovernanceError {
-    fn from(_error: soroban_sdk::Error) -> Self {
-        GovernanceError::Unauthorized
-    }
+    // Role management errors
+    RoleNotFound = 2023,
+    LastAdmin = 2024,
+
+    // Token voting errors
+    InvalidVotingConfig = 2025,
+    WrongVotingMode = 2026,
+    VotingClosed = 2027,
+    VotingNotEnded = 2028,
+    AlreadyVoted = 2029,
+    NoVotingPower = 2030,
+
+    // Expiry errors
+    ProposalExpired = 2031,
+    NotExpired = 2032,
+
+    // Signature approval errors
+    SigningKeyNotFound = 2033,
+
+    // Rejection errors
+    DuplicateRejection = 2034,
+    RejectionNotFound = 2035,
+
+    // Later token voting and execution errors
+    VoteOverflow = 2036,
+    SimulationNotPassed = 2037,
 }
 
 pub struct GovernanceManager;
@@ -111,13 +443,13 @@ impl ValidationModule {
     #[allow(clippy::too_many_arguments)]
     pub fn validate_proposal_params(
         env: &Env,
-        new_contract_hash: &Symbol,
+        new_contract_hash: &BytesN<32>,
         target_contract: &Address,
         approval_threshold: u32,
         approvers: &Vec<Address>,
         timelock_delay: u64,
         current_version: u32,
-        proposed_version: u32,
+        params: &ProposalParams,
     ) -> Result<(), GovernanceError> {
         // Validate hash format
         Self::validate_hash_format(new_contract_hash)?;
@@ -127,6 +459,9 @@ impl ValidationModule {
 
         // Validate threshold
         Self::validate_threshold(approval_threshold, approvers.len())?;
+        if params.rejection_threshold > approvers.len() {
+            return Err(GovernanceError::InvalidThreshold);
+        }
 
         // Validate timelock
         Self::validate_timelock(timelock_delay)?;
@@ -135,16 +470,81 @@ impl ValidationModule {
         Self::validate_approvers_unique(approvers)?;
 
         // Validate version compatibility
-        Self::validate_version_compatibility(current_version, proposed_version)?;
+        Self::validate_version_compatibility(current_version, params.proposed_version)?;
+
+        // Approvals must be possible before the proposal expires
+        if params.cooling_off_period >= PROPOSAL_LIFETIME {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        Ok(())
+    }
+
+    /// Validate parameters of a generic action proposal
+    pub fn validate_action_params(
+        env: &Env,
+        call: &ContractCall,
+        approval_threshold: u32,
+        approvers: &Vec<Address>,
+        timelock_delay: u64,
+    ) -> Result<(), GovernanceError> {
+        Self::validate_call(env, call)?;
+        Self::validate_threshold(approval_threshold, approvers.len())?;
+        Self::validate_timelock(timelock_delay)?;
+        Self::validate_approvers_unique(approvers)?;
+
+        Ok(())
+    }
+
+    /// Validate the title of a new proposal
+    pub fn validate_metadata(metadata: &ProposalMetadata) -> Result<(), GovernanceError> {
+        if metadata.title.len() == 0 || metadata.title.len() > MAX_TITLE_LEN {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        Ok(())
+    }
+
+    /// Validate parameters of a batch proposal
+    pub fn validate_batch_params(
+        env: &Env,
+        calls: &Vec<ContractCall>,
+        approval_threshold: u32,
+        approvers: &Vec<Address>,
+        timelock_delay: u64,
+    ) -> Result<(), GovernanceError> {
+        if calls.is_empty() || calls.len() > MAX_BATCH_ACTIONS {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        for call in calls.iter() {
+            Self::validate_call(env, &call)?;
+        }
+        Self::validate_threshold(approval_threshold, approvers.len())?;
+        Self::validate_timelock(timelock_delay)?;
+        Self::validate_approvers_unique(approvers)?;
 
         Ok(())
     }
 
-    /// Validate contract hash format (must not be empty)
-    fn validate_hash_format(hash: &Symbol) -> Result<(), GovernanceError> {
-        // Check if hash is empty
-        let hash_str = hash.to_string();
-        if hash_str.is_empty() {
+    /// Validate the target of a single contract call
+    fn validate_call(env: &Env, call: &ContractCall) -> Result<(), GovernanceError> {
+        // The governing contract cannot re-enter itself
+        if call.target == env.current_contract_address() {
+            return Err(GovernanceError::InvalidContractAddress);
+        }
+
+        Self::validate_contract_address(env, &call.target)
+    }
+
+    /// Validate contract hash format (must not be all zeroes)
+    ///
+    /// The host has no call for checking that a code entry exists, so a hash
+    /// that was never uploaded passes here. It is caught at execution instead:
+    /// installing unknown WASM traps, reverting the whole execution and leaving
+    /// the proposal `Approved`.
+    fn validate_hash_format(hash: &BytesN<32>) -> Result<(), GovernanceError> {
+        if hash.to_array() == [0u8; 32] {
             return Err(GovernanceError::InvalidHashFormat);
         }
         Ok(())
@@ -187,6 +587,23 @@ impl ValidationModule {
         Ok(())
     }
 
+    /// Raise the threshold and timelock of a breaking change
+    ///
+    /// Breaking changes need a two-thirds supermajority of the approver set
+    /// and at least `BREAKING_CHANGE_MIN_TIMELOCK`; smaller values are
+    /// bumped rather than rejected.
+    pub fn breaking_change_requirements(
+        approval_threshold: u32,
+        approver_count: u32,
+        timelock_delay: u64,
+    ) -> (u32, u64) {
+        let supermajority = (approver_count * 2).div_ceil(3);
+        (
+            approval_threshold.max(supermajority),
+            timelock_delay.max(BREAKING_CHANGE_MIN_TIMELOCK),
+        )
+    }
+
     /// Validate version compatibility (proposed must be greater than current)
     fn validate_version_compatibility(current: u32, proposed: u32) -> Result<(), GovernanceError> {
         if proposed <= current {
@@ -196,6 +613,220 @@ impl ValidationModule {
     }
 }
 
+/// Storage module for per-proposal persistent entries
+pub struct StorageModule;
+
+impl StorageModule {
+    /// Load a proposal and keep its entry alive
+    pub fn load_proposal(env: &Env, proposal_id: u64) -> Result<UpgradeProposal, GovernanceError> {
+        Self::read(env, &GovernanceKey::Proposal(proposal_id))
+            .ok_or(GovernanceError::ProposalNotFound)
+    }
+
+    /// Store a proposal under its own key
+    pub fn save_proposal(env: &Env, proposal: &UpgradeProposal) {
+        Self::write(env, &GovernanceKey::Proposal(proposal.id), proposal);
+    }
+
+    /// Whether `approver` has approved the proposal
+    pub fn has_approval(env: &Env, proposal_id: u64, approver: &Address) -> bool {
+        let key = GovernanceKey::Approval(proposal_id, approver.clone());
+        env.storage().persistent().has(&key)
+    }
+
+    fn set_approval(env: &Env, proposal_id: u64, approver: &Address) {
+        Self::write(
+            env,
+            &GovernanceKey::Approval(proposal_id, approver.clone()),
+            &true,
+        );
+    }
+
+    /// Whether `approver` has rejected the proposal
+    pub fn has_rejection(env: &Env, proposal_id: u64, approver: &Address) -> bool {
+        let key = GovernanceKey::Rejection(proposal_id, approver.clone());
+        env.storage().persistent().has(&key)
+    }
+
+    fn set_rejection(env: &Env, proposal_id: u64, approver: &Address) {
+        Self::write(
+            env,
+            &GovernanceKey::Rejection(proposal_id, approver.clone()),
+            &true,
+        );
+    }
+
+    fn remove_rejection(env: &Env, proposal_id: u64, approver: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&GovernanceKey::Rejection(proposal_id, approver.clone()));
+    }
+
+    fn remove_approval(env: &Env, proposal_id: u64, approver: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&GovernanceKey::Approval(proposal_id, approver.clone()));
+        env.storage()
+            .persistent()
+            .remove(&GovernanceKey::ApprovalTimestamp(
+                proposal_id,
+                approver.clone(),
+            ));
+    }
+
+    fn read<V: TryFromVal<Env, Val>>(env: &Env, key: &GovernanceKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            Self::extend(env, key);
+        }
+        value
+    }
+
+    fn write<V: IntoVal<Env, Val>>(env: &Env, key: &GovernanceKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::extend(env, key);
+    }
+
+    fn extend(env: &Env, key: &GovernanceKey) {
+        env.storage().persistent().extend_ttl(
+            key,
+            GOVERNANCE_TTL_THRESHOLD,
+            GOVERNANCE_TTL_EXTEND_TO,
+        );
+    }
+
+    /// Move entries from the legacy shared maps into per-proposal keys
+    ///
+    /// Contracts deployed before per-proposal storage kept everything in the
+    /// `props`, `apprv` and `appr_ts` maps, with proposals in the
+    /// `LegacyUpgradeProposal` layout. At most `max_entries` entries are moved per call so large histories can be
+    /// split over several transactions; the number of entries still waiting
+    /// is returned and reaches 0 once every legacy map has been removed.
+    pub fn migrate_legacy_storage(
+        env: &Env,
+        admin: Address,
+        max_entries: u32,
+    ) -> Result<u32, GovernanceError> {
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
+
+        let mut budget = max_entries;
+        let mut remaining = 0;
+        remaining += Self::migrate_map(
+            env,
+            symbol_short!("props"),
+            &mut budget,
+            |_id: u64, legacy: LegacyUpgradeProposal| {
+                let proposal = legacy.into_proposal(env);
+                Self::save_proposal(env, &proposal);
+                Self::index_by_target(env, &proposal);
+            },
+        );
+        remaining += Self::migrate_map(
+            env,
+            symbol_short!("apprv"),
+            &mut budget,
+            |(id, approver): (u64, Address), approved: bool| {
+                Self::write(env, &GovernanceKey::Approval(id, approver), &approved)
+            },
+        );
+        remaining += Self::migrate_map(
+            env,
+            symbol_short!("appr_ts"),
+            &mut budget,
+            |(id, approver): (u64, Address), timestamp: u64| {
+                Self::write(
+                    env,
+                    &GovernanceKey::ApprovalTimestamp(id, approver),
+                    &timestamp,
+                )
+            },
+        );
+
+        Ok(remaining)
+    }
+
+    /// Move up to `budget` entries of one legacy map, returning how many are left
+    fn migrate_map<K, V, F>(env: &Env, legacy_key: Symbol, budget: &mut u32, store: F) -> u32
+    where
+        K: Clone + IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        F: Fn(K, V),
+    {
+        let mut legacy: soroban_sdk::Map<K, V> = match env.storage().persistent().get(&legacy_key) {
+            Some(map) => map,
+            None => return 0,
+        };
+
+        for key in legacy.keys().iter() {
+            if *budget == 0 {
+                break;
+            }
+            if let Some(value) = legacy.get(key.clone()) {
+                store(key.clone(), value);
+            }
+            legacy.remove(key);
+            *budget -= 1;
+        }
+
+        if legacy.is_empty() {
+            env.storage().persistent().remove(&legacy_key);
+        } else {
+            env.storage().persistent().set(&legacy_key, &legacy);
+        }
+        legacy.len()
+    }
+
+    /// Record a proposal in the list of proposals for its target contract
+    ///
+    /// A batch is indexed under every contract one of its calls targets.
+    pub fn index_by_target(env: &Env, proposal: &UpgradeProposal) {
+        Self::index_target(env, &proposal.target_contract, proposal.id);
+        if let ProposalAction::Batch(calls) = &proposal.action {
+            for call in calls.iter() {
+                Self::index_target(env, &call.target, proposal.id);
+            }
+        }
+    }
+
+    fn index_target(env: &Env, target_contract: &Address, proposal_id: u64) {
+        let count = Self::target_proposal_count(env, target_contract);
+
+        // A proposal is indexed all at once, so a repeat target is always the last entry
+        if count > 0 && Self::target_proposal(env, target_contract, count - 1) == Some(proposal_id)
+        {
+            return;
+        }
+
+        Self::write(
+            env,
+            &GovernanceKey::TargetProposal(target_contract.clone(), count),
+            &proposal_id,
+        );
+        Self::write(
+            env,
+            &GovernanceKey::TargetProposalCount(target_contract.clone()),
+            &(count + 1),
+        );
+    }
+
+    /// Number of proposals targeting `target_contract`
+    pub fn target_proposal_count(env: &Env, target_contract: &Address) -> u32 {
+        Self::read(
+            env,
+            &GovernanceKey::TargetProposalCount(target_contract.clone()),
+        )
+        .unwrap_or(0)
+    }
+
+    /// ID of the `index`-th proposal targeting `target_contract`, oldest first
+    pub fn target_proposal(env: &Env, target_contract: &Address, index: u32) -> Option<u64> {
+        Self::read(
+            env,
+            &GovernanceKey::TargetProposal(target_contract.clone(), index),
+        )
+    }
+}
+
 /// Halt module for emergency proposal halting
 pub struct HaltModule;
 
@@ -208,31 +839,26 @@ impl HaltModule {
         reason: Symbol,
     ) -> Result<(), GovernanceError> {
         // Validate admin role
-        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin);
-
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
 
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         // Cannot halt already executed proposals
         if proposal.executed || proposal.status == ProposalStatus::Executed {
             return Err(GovernanceError::CannotHaltExecuted);
         }
 
+        // A veto is final; halting would let it be resumed
+        if proposal.status == ProposalStatus::Vetoed {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
         // Update proposal to halted status
         proposal.status = ProposalStatus::Halted;
         proposal.halt_reason = reason.clone();
         proposal.halted_at = env.ledger().timestamp();
 
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit halt event
         EventEmitter::proposal_halted(
@@ -256,24 +882,21 @@ impl HaltModule {
         new_timelock_delay: u64,
     ) -> Result<(), GovernanceError> {
         // Validate admin role
-        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin);
-
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
 
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         // Can only resume halted proposals
         if proposal.status != ProposalStatus::Halted {
             return Err(GovernanceError::NotHalted);
         }
 
+        // The new delay must meet the same minimum as at creation
+        ValidationModule::validate_timelock(new_timelock_delay)?;
+        if proposal.breaking_change && new_timelock_delay < BREAKING_CHANGE_MIN_TIMELOCK {
+            return Err(GovernanceError::TimelockTooShort);
+        }
+
         // Only original proposer or admin can resume
         if proposal.proposer != admin {
             // Check if admin has admin role (already checked above)
@@ -288,15 +911,15 @@ impl HaltModule {
             proposal.status = ProposalStatus::Pending;
         }
 
-        // Set new execution time
+        // Set new execution time. The expiry is kept, so a halt cannot give a
+        // stale approval a fresh execution window.
         proposal.execution_time = env.ledger().timestamp() + new_timelock_delay;
         proposal.halt_reason = symbol_short!("");
         proposal.halted_at = 0;
 
         let new_exec_time = proposal.execution_time;
 
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit resume event
         EventEmitter::proposal_resumed(
@@ -314,18 +937,9 @@ impl HaltModule {
 
     /// Check if proposal is halted
     pub fn is_halted(env: &Env, proposal_id: u64) -> bool {
-        let proposals_key = symbol_short!("props");
-        let proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
-
-        if let Some(proposal) = proposals.get(proposal_id) {
-            proposal.status == ProposalStatus::Halted
-        } else {
-            false
-        }
+        StorageModule::load_proposal(env, proposal_id)
+            .map(|proposal| proposal.status == ProposalStatus::Halted)
+            .unwrap_or(false)
     }
 }
 
@@ -338,20 +952,22 @@ impl ApprovalModule {
         env: &Env,
         proposal_id: u64,
         approver: Address,
+        rationale: Rationale,
     ) -> Result<(), GovernanceError> {
         // Validate approver has permission
-        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver);
+        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver)?;
 
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        Self::record_approval(env, proposal_id, approver, rationale)
+    }
 
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+    /// Count an approval from an approver whose role was already checked
+    fn record_approval(
+        env: &Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         // Check cooling-off period
         Self::check_cooling_off_period(env, &proposal)?;
@@ -361,26 +977,32 @@ impl ApprovalModule {
             return Err(GovernanceError::InvalidProposal);
         }
 
+        // Token-weighted proposals are decided by holders, not approvers
+        if proposal.voting_mode != VotingMode::Multisig {
+            return Err(GovernanceError::WrongVotingMode);
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(GovernanceError::ProposalExpired);
+        }
+
         // Validate approver is in the list
         if !proposal.approvers.iter().any(|a| a == approver) {
             return Err(GovernanceError::Unauthorized);
         }
 
         // Check for duplicate approval
-        let approvals_key = symbol_short!("apprv");
-        let mut approvals: soroban_sdk::Map<(u64, Address), bool> = env
-            .storage()
-            .persistent()
-            .get(&approvals_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
-
-        if approvals.get((proposal_id, approver.clone())).is_some() {
+        if StorageModule::has_approval(env, proposal_id, &approver) {
             return Err(GovernanceError::DuplicateApproval);
         }
 
+        // A rejection must be revoked before approving
+        if StorageModule::has_rejection(env, proposal_id, &approver) {
+            return Err(GovernanceError::DuplicateRejection);
+        }
+
         // Record approval
-        approvals.set((proposal_id, approver.clone()), true);
-        env.storage().persistent().set(&approvals_key, &approvals);
+        StorageModule::set_approval(env, proposal_id, &approver);
 
         // Record approval timestamp
         Self::record_approval_timestamp(env, proposal_id, &approver, env.ledger().timestamp());
@@ -394,13 +1016,13 @@ impl ApprovalModule {
             // Update execution time from final approval timestamp
             proposal.execution_time =
                 env.ledger().timestamp() + (proposal.execution_time - proposal.created_at);
+            proposal.expires_at = proposal.execution_time + EXECUTION_WINDOW;
         }
 
         let current_approvals = proposal.approvals_count;
         let threshold = proposal.approval_threshold;
 
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit proposal approved event
         EventEmitter::proposal_approved(
@@ -410,6 +1032,7 @@ impl ApprovalModule {
                 approver,
                 current_approvals,
                 threshold,
+                rationale,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -422,20 +1045,12 @@ impl ApprovalModule {
         env: &Env,
         proposal_id: u64,
         approver: Address,
+        rationale: Rationale,
     ) -> Result<(), GovernanceError> {
         // Validate approver has permission
-        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver);
+        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver)?;
 
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
-
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         // Cannot revoke if already executed
         if proposal.executed {
@@ -443,14 +1058,7 @@ impl ApprovalModule {
         }
 
         // Check if approval exists
-        let approvals_key = symbol_short!("apprv");
-        let mut approvals: soroban_sdk::Map<(u64, Address), bool> = env
-            .storage()
-            .persistent()
-            .get(&approvals_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
-
-        if approvals.get((proposal_id, approver.clone())).is_none() {
+        if !StorageModule::has_approval(env, proposal_id, &approver) {
             return Err(GovernanceError::ApprovalNotFound);
         }
 
@@ -460,16 +1068,14 @@ impl ApprovalModule {
         }
 
         // Remove approval
-        approvals.remove((proposal_id, approver.clone()));
-        env.storage().persistent().set(&approvals_key, &approvals);
+        StorageModule::remove_approval(env, proposal_id, &approver);
 
         // Decrement approval count
         if proposal.approvals_count > 0 {
             proposal.approvals_count -= 1;
         }
 
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit approval revoked event
         EventEmitter::approval_revoked(
@@ -477,6 +1083,110 @@ impl ApprovalModule {
             ApprovalRevokedEvent {
                 proposal_id,
                 approver,
+                rationale,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Count a rejection; the proposal is rejected once `rejection_threshold` is reached
+    pub fn reject(
+        env: &Env,
+        proposal_id: u64,
+        rejector: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &rejector, GovernanceRole::Approver)?;
+
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+
+        if proposal.status != ProposalStatus::Pending {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(GovernanceError::ProposalExpired);
+        }
+
+        // Validate rejector is in the list
+        if !proposal.approvers.iter().any(|a| a == rejector) {
+            return Err(GovernanceError::Unauthorized);
+        }
+
+        if StorageModule::has_rejection(env, proposal_id, &rejector) {
+            return Err(GovernanceError::DuplicateRejection);
+        }
+
+        // An approval must be revoked before rejecting
+        if StorageModule::has_approval(env, proposal_id, &rejector) {
+            return Err(GovernanceError::DuplicateApproval);
+        }
+
+        StorageModule::set_rejection(env, proposal_id, &rejector);
+        proposal.rejections_count += 1;
+
+        if proposal.rejections_count >= proposal.rejection_threshold {
+            proposal.status = ProposalStatus::Rejected;
+        }
+
+        let current_rejections = proposal.rejections_count;
+        let threshold = proposal.rejection_threshold;
+
+        StorageModule::save_proposal(env, &proposal);
+
+        // Emit proposal rejected event
+        EventEmitter::proposal_rejected(
+            env,
+            ProposalRejectedEvent {
+                proposal_id,
+                rejector,
+                current_rejections,
+                threshold,
+                rationale,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw a rejection while the proposal is still pending
+    pub fn revoke_rejection(
+        env: &Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver)?;
+
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+
+        if !StorageModule::has_rejection(env, proposal_id, &approver) {
+            return Err(GovernanceError::RejectionNotFound);
+        }
+
+        // Reaching the rejection threshold is final
+        if proposal.status != ProposalStatus::Pending {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(GovernanceError::ProposalExpired);
+        }
+
+        StorageModule::remove_rejection(env, proposal_id, &approver);
+        proposal.rejections_count -= 1;
+
+        StorageModule::save_proposal(env, &proposal);
+
+        EventEmitter::rejection_revoked(
+            env,
+            RejectionRevokedEvent {
+                proposal_id,
+                approver,
+                rationale,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -486,16 +1196,7 @@ impl ApprovalModule {
 
     /// Get time remaining until execution possible
     pub fn get_time_to_execution(env: &Env, proposal_id: u64) -> Result<u64, GovernanceError> {
-        let proposals_key = symbol_short!("props");
-        let proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
-
-        let proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         let current_time = env.ledger().timestamp();
         if current_time >= proposal.execution_time {
@@ -507,15 +1208,11 @@ impl ApprovalModule {
 
     /// Record approval timestamp
     fn record_approval_timestamp(env: &Env, proposal_id: u64, approver: &Address, timestamp: u64) {
-        let timestamp_key = symbol_short!("appr_ts");
-        let mut timestamps: soroban_sdk::Map<(u64, Address), u64> = env
-            .storage()
-            .persistent()
-            .get(&timestamp_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
-
-        timestamps.set((proposal_id, approver.clone()), timestamp);
-        env.storage().persistent().set(&timestamp_key, &timestamps);
+        StorageModule::write(
+            env,
+            &GovernanceKey::ApprovalTimestamp(proposal_id, approver.clone()),
+            &timestamp,
+        );
     }
 
     /// Check if cooling-off period has passed
@@ -534,22 +1231,468 @@ impl ApprovalModule {
     }
 }
 
-impl GovernanceManager {
-    /// Validate that an address has a specific role
-    pub fn require_role(env: &Env, address: &Address, required_role: GovernanceRole) {
-        let roles_key = symbol_short!("roles");
-        let role_map: soroban_sdk::Map<Address, GovernanceRole> = env
-            .storage()
+/// Guardian module for vetoing proposals
+///
+/// Guardians sit outside the Admin > Approver > Executor hierarchy: they
+/// cannot propose, approve or execute, and only they can veto.
+pub struct GuardianModule;
+
+impl GuardianModule {
+    /// Veto a pending or approved proposal before it executes
+    pub fn veto_proposal(
+        env: &Env,
+        proposal_id: u64,
+        guardian: Address,
+        reason: Symbol,
+    ) -> Result<(), GovernanceError> {
+        if RoleModule::get_role(env, &guardian) != Some(GovernanceRole::Guardian) {
+            return Err(GovernanceError::Unauthorized);
+        }
+
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+
+        let previous_status = proposal.status;
+        if previous_status != ProposalStatus::Pending && previous_status != ProposalStatus::Approved
+        {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        proposal.status = ProposalStatus::Vetoed;
+        StorageModule::save_proposal(env, &proposal);
+
+        EventEmitter::proposal_vetoed(
+            env,
+            ProposalVetoedEvent {
+                proposal_id,
+                guardian,
+                reason,
+                previous_status,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+}
+
+/// Signature module for approvals collected off-chain
+///
+/// Each approver registers an ed25519 public key once. They then sign
+/// `approval_digest` offline and any relayer submits the signatures together.
+/// The digest covers the network, this contract, the proposal, the approver
+/// and the approver's nonce, and the nonce advances with every accepted
+/// signature, so a signature cannot be replayed.
+pub struct SignatureModule;
+
+impl SignatureModule {
+    /// Register the key `approver` signs approvals with
+    pub fn set_signing_key(
+        env: &Env,
+        approver: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &approver, GovernanceRole::Approver)?;
+
+        StorageModule::write(
+            env,
+            &GovernanceKey::SigningKey(approver.clone()),
+            &public_key,
+        );
+
+        EventEmitter::signing_key_set(
+            env,
+            SigningKeySetEvent {
+                approver,
+                public_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Key registered by `approver`, if any
+    pub fn get_signing_key(env: &Env, approver: &Address) -> Option<BytesN<32>> {
+        StorageModule::read(env, &GovernanceKey::SigningKey(approver.clone()))
+    }
+
+    /// Nonce the approver's next signature must be made over
+    pub fn get_nonce(env: &Env, approver: &Address) -> u64 {
+        StorageModule::read(env, &GovernanceKey::SignatureNonce(approver.clone())).unwrap_or(0)
+    }
+
+    /// Digest `approver` signs to approve `proposal_id`
+    ///
+    /// sha256(domain || network id || contract || proposal id || approver || nonce
+    /// || rationale), where the rationale is a 0 byte when absent and a 1 byte
+    /// followed by the hash when present.
+    pub fn approval_digest(
+        env: &Env,
+        proposal_id: u64,
+        approver: &Address,
+        rationale: &Rationale,
+    ) -> BytesN<32> {
+        let mut message = Bytes::from_slice(env, APPROVAL_DOMAIN);
+        message.append(&env.ledger().network_id().into());
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.extend_from_array(&proposal_id.to_be_bytes());
+        message.append(&approver.clone().to_xdr(env));
+        message.extend_from_array(&Self::get_nonce(env, approver).to_be_bytes());
+        match rationale {
+            Rationale::Hash(hash) => {
+                message.push_back(1);
+                message.append(&hash.clone().into());
+            }
+            Rationale::None => message.push_back(0),
+        }
+        env.crypto().sha256(&message)
+    }
+
+    /// Apply approvals signed off-chain, in order
+    ///
+    /// An invalid signature traps, reverting every approval in the call.
+    pub fn approve_with_signatures(
+        env: &Env,
+        proposal_id: u64,
+        signatures: Vec<ApprovalSignature>,
+    ) -> Result<(), GovernanceError> {
+        for entry in signatures.iter() {
+            GovernanceManager::require_role(env, &entry.approver, GovernanceRole::Approver)?;
+
+            let public_key = Self::get_signing_key(env, &entry.approver)
+                .ok_or(GovernanceError::SigningKeyNotFound)?;
+            let digest = Self::approval_digest(env, proposal_id, &entry.approver, &entry.rationale);
+            env.crypto()
+                .ed25519_verify(&public_key, &digest.into(), &entry.signature);
+
+            let nonce = Self::get_nonce(env, &entry.approver);
+            StorageModule::write(
+                env,
+                &GovernanceKey::SignatureNonce(entry.approver.clone()),
+                &(nonce + 1),
+            );
+
+            ApprovalModule::record_approval(env, proposal_id, entry.approver, entry.rationale)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Role module for managing governance membership
+pub struct RoleModule;
+
+impl RoleModule {
+    /// Grant a role to a member, replacing any role it already holds
+    pub fn grant_role(
+        env: &Env,
+        admin: Address,
+        member: Address,
+        role: GovernanceRole,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
+
+        let mut roles = Self::load_roles(env);
+
+        // Demoting the only admin would lock everyone out
+        if let Some(GovernanceRole::Admin) = roles.get(member.clone()) {
+            if role != GovernanceRole::Admin && Self::count_admins(&roles) == 1 {
+                return Err(GovernanceError::LastAdmin);
+            }
+        }
+
+        roles.set(member.clone(), role);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("roles"), &roles);
+
+        EventEmitter::role_granted(
+            env,
+            RoleGrantedEvent {
+                member,
+                role,
+                granted_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Revoke whatever role a member holds
+    pub fn revoke_role(env: &Env, admin: Address, member: Address) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
+
+        let mut roles = Self::load_roles(env);
+        let role = roles
+            .get(member.clone())
+            .ok_or(GovernanceError::RoleNotFound)?;
+
+        if role == GovernanceRole::Admin && Self::count_admins(&roles) == 1 {
+            return Err(GovernanceError::LastAdmin);
+        }
+
+        roles.remove(member.clone());
+        env.storage()
             .persistent()
-            .get(&roles_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
+            .set(&symbol_short!("roles"), &roles);
+
+        EventEmitter::role_revoked(
+            env,
+            RoleRevokedEvent {
+                member,
+                role,
+                revoked_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the role held by a member, if any
+    pub fn get_role(env: &Env, member: &Address) -> Option<GovernanceRole> {
+        Self::load_roles(env).get(member.clone())
+    }
+
+    /// List every member holding exactly `role`
+    pub fn list_members(env: &Env, role: GovernanceRole) -> Vec<Address> {
+        let mut members = Vec::new(env);
+        for (member, member_role) in Self::load_roles(env).iter() {
+            if member_role == role {
+                members.push_back(member);
+            }
+        }
+        members
+    }
+
+    fn load_roles(env: &Env) -> soroban_sdk::Map<Address, GovernanceRole> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("roles"))
+            .unwrap_or_else(|| soroban_sdk::Map::new(env))
+    }
+
+    fn count_admins(roles: &soroban_sdk::Map<Address, GovernanceRole>) -> u32 {
+        roles
+            .values()
+            .iter()
+            .filter(|role| *role == GovernanceRole::Admin)
+            .count() as u32
+    }
+}
+
+/// Voting module for token-weighted proposal decisions
+pub struct VotingModule;
+
+impl VotingModule {
+    /// Switch a fresh pending proposal from multisig approval to a token vote
+    ///
+    /// The snapshot is the current ledger; votes are accepted from the next
+    /// ledger on, so balances moved within the snapshot ledger cannot be
+    /// double counted.
+    pub fn enable_token_voting(
+        env: &Env,
+        proposal_id: u64,
+        admin: Address,
+        token: Address,
+        voting_period: u64,
+        quorum_bps: u32,
+        majority_bps: u32,
+    ) -> Result<(), GovernanceError> {
+        GovernanceManager::require_role(env, &admin, GovernanceRole::Admin)?;
+
+        if voting_period == 0 || quorum_bps > 10_000 || majority_bps == 0 || majority_bps >= 10_000
+        {
+            return Err(GovernanceError::InvalidVotingConfig);
+        }
+
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+
+        if proposal.status != ProposalStatus::Pending || proposal.approvals_count > 0 {
+            return Err(GovernanceError::InvalidProposal);
+        }
+
+        // A period too long to represent is as invalid as an empty one
+        let voting_end = env
+            .ledger()
+            .timestamp()
+            .checked_add(voting_period)
+            .ok_or(GovernanceError::InvalidVotingConfig)?;
+        let finalize_by = voting_end
+            .checked_add(EXECUTION_WINDOW)
+            .ok_or(GovernanceError::InvalidVotingConfig)?;
+        proposal.voting_mode = VotingMode::TokenWeighted(TokenVotingConfig {
+            token,
+            snapshot_ledger: env.ledger().sequence(),
+            voting_end,
+            quorum_bps,
+            majority_bps,
+        });
+        // Leave time to finalize a vote that outlasts the approval lifetime
+        proposal.expires_at = proposal.expires_at.max(finalize_by);
+
+        StorageModule::save_proposal(env, &proposal);
+
+        Ok(())
+    }
+
+    /// Cast a vote weighted by the voter's balance at the snapshot ledger
+    pub fn cast_vote(
+        env: &Env,
+        proposal_id: u64,
+        voter: Address,
+        choice: VoteChoice,
+    ) -> Result<i128, GovernanceError> {
+        let proposal = GovernanceManager::get_proposal(env, proposal_id)?;
+        let config = Self::token_config(&proposal)?;
+
+        if proposal.status != ProposalStatus::Pending
+            || env.ledger().timestamp() >= config.voting_end
+            || env.ledger().sequence() <= config.snapshot_ledger
+        {
+            return Err(GovernanceError::VotingClosed);
+        }
+
+        let vote_key = GovernanceKey::Vote(proposal_id, voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+
+        let weight = VotingTokenClient::new(env, &config.token)
+            .voting_power_at(&voter, &config.snapshot_ledger);
+        if weight <= 0 {
+            return Err(GovernanceError::NoVotingPower);
+        }
+
+        StorageModule::write(env, &vote_key, &choice);
+
+        let mut tally = Self::get_tally(env, proposal_id);
+        let votes = match choice {
+            VoteChoice::For => &mut tally.for_votes,
+            VoteChoice::Against => &mut tally.against_votes,
+            VoteChoice::Abstain => &mut tally.abstain_votes,
+        };
+        *votes = votes
+            .checked_add(weight)
+            .ok_or(GovernanceError::VoteOverflow)?;
+        Self::set_tally(env, proposal_id, &tally);
+
+        EventEmitter::vote_cast(
+            env,
+            VoteCastEvent {
+                proposal_id,
+                voter,
+                choice,
+                weight,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(weight)
+    }
+
+    /// Close a token vote once its period ends (callable by anyone)
+    ///
+    /// A vote passes when turnout reaches the quorum and the for-share of
+    /// decisive (for + against) votes exceeds the majority threshold.
+    pub fn finalize_vote(env: &Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+        let config = Self::token_config(&proposal)?;
+
+        if proposal.status != ProposalStatus::Pending {
+            return Err(GovernanceError::InvalidProposal);
+        }
+        if env.ledger().timestamp() < config.voting_end {
+            return Err(GovernanceError::VotingNotEnded);
+        }
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(GovernanceError::ProposalExpired);
+        }
+
+        let tally = Self::get_tally(env, proposal_id);
+        let total_supply =
+            VotingTokenClient::new(env, &config.token).total_supply_at(&config.snapshot_ledger);
+
+        // Token amounts can be close to i128::MAX, so every step is checked
+        let decisive = tally
+            .for_votes
+            .checked_add(tally.against_votes)
+            .ok_or(GovernanceError::VoteOverflow)?;
+        let turnout = decisive
+            .checked_add(tally.abstain_votes)
+            .ok_or(GovernanceError::VoteOverflow)?;
+        let quorum_reached =
+            Self::scale(turnout, 10_000)? >= Self::scale(total_supply, config.quorum_bps)?;
+        let majority_reached = decisive > 0
+            && Self::scale(tally.for_votes, 10_000)? > Self::scale(decisive, config.majority_bps)?;
+
+        let approved = quorum_reached && majority_reached;
+        if approved {
+            proposal.status = ProposalStatus::Approved;
+            // Timelock runs from the end of the vote, as it does from the final approval
+            proposal.execution_time =
+                env.ledger().timestamp() + (proposal.execution_time - proposal.created_at);
+            proposal.expires_at = proposal.execution_time + EXECUTION_WINDOW;
+        } else {
+            proposal.status = ProposalStatus::Rejected;
+        }
 
-        let user_role = role_map
-            .get(address.clone())
-            .unwrap_or(GovernanceRole::Executor);
+        let status = proposal.status;
+        StorageModule::save_proposal(env, &proposal);
+
+        EventEmitter::vote_finalized(
+            env,
+            VoteFinalizedEvent {
+                proposal_id,
+                approved,
+                for_votes: tally.for_votes,
+                against_votes: tally.against_votes,
+                abstain_votes: tally.abstain_votes,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(status)
+    }
 
-        if user_role > required_role {
-            panic!("UNAUTH");
+    /// Multiply a vote amount by a basis-point factor
+    fn scale(amount: i128, factor: u32) -> Result<i128, GovernanceError> {
+        amount
+            .checked_mul(factor as i128)
+            .ok_or(GovernanceError::VoteOverflow)
+    }
+
+    /// Get the current tally of a token vote
+    pub fn get_tally(env: &Env, proposal_id: u64) -> VoteTally {
+        StorageModule::read(env, &GovernanceKey::Tally(proposal_id)).unwrap_or_default()
+    }
+
+    fn set_tally(env: &Env, proposal_id: u64, tally: &VoteTally) {
+        StorageModule::write(env, &GovernanceKey::Tally(proposal_id), tally);
+    }
+
+    fn token_config(proposal: &UpgradeProposal) -> Result<TokenVotingConfig, GovernanceError> {
+        match &proposal.voting_mode {
+            VotingMode::TokenWeighted(config) => Ok(config.clone()),
+            VotingMode::Multisig => Err(GovernanceError::WrongVotingMode),
+        }
+    }
+}
+
+impl GovernanceManager {
+    /// Validate that an address has a specific role
+    ///
+    /// Roles are ordered Admin > Approver > Executor, so a higher role may act
+    /// for a lower one. Guardians and addresses without a role cannot act.
+    pub fn require_role(
+        env: &Env,
+        address: &Address,
+        required_role: GovernanceRole,
+    ) -> Result<(), GovernanceError> {
+        match RoleModule::get_role(env, address) {
+            Some(role) if role <= required_role => Ok(()),
+            _ => Err(GovernanceError::Unauthorized),
         }
     }
 
@@ -558,15 +1701,20 @@ impl GovernanceManager {
     pub fn propose_upgrade(
         env: &Env,
         proposer: Address,
-        new_contract_hash: Symbol,
+        new_contract_hash: BytesN<32>,
         target_contract: Address,
-        description: Symbol,
+        metadata: ProposalMetadata,
         approval_threshold: u32,
         approvers: Vec<Address>,
         timelock_delay: u64,
+        params: ProposalParams,
     ) -> Result<u64, GovernanceError> {
         // Validate proposer is admin
-        Self::require_role(env, &proposer, GovernanceRole::Admin);
+        Self::require_role(env, &proposer, GovernanceRole::Admin)?;
+
+        ValidationModule::validate_metadata(&metadata)?;
+
+        let current_version = Self::current_version(env, &target_contract)?;
 
         // Validate proposal parameters
         ValidationModule::validate_proposal_params(
@@ -576,10 +1724,157 @@ impl GovernanceManager {
             approval_threshold,
             &approvers,
             timelock_delay,
-            1, // current_version - default
-            2, // proposed_version - default
+            current_version,
+            &params,
+        )?;
+
+        let (approval_threshold, timelock_delay) = if params.breaking_change {
+            ValidationModule::breaking_change_requirements(
+                approval_threshold,
+                approvers.len(),
+                timelock_delay,
+            )
+        } else {
+            (approval_threshold, timelock_delay)
+        };
+
+        Self::create_proposal(
+            env,
+            proposer,
+            new_contract_hash,
+            target_contract,
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+            ProposalAction::Upgrade,
+            current_version,
+            params,
+        )
+    }
+
+    /// Read the version a target contract currently runs
+    ///
+    /// A contract cannot call itself, so a self-governed contract's version is
+    /// read from `VERSION_KEY`; any other target must expose `get_version`.
+    pub fn current_version(env: &Env, target_contract: &Address) -> Result<u32, GovernanceError> {
+        if *target_contract == env.current_contract_address() {
+            return Ok(env.storage().persistent().get(&VERSION_KEY).unwrap_or(0));
+        }
+
+        match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            target_contract,
+            &Symbol::new(env, "get_version"),
+            Vec::new(env),
+        ) {
+            Ok(Ok(version)) => Ok(version),
+            _ => Err(GovernanceError::InvalidVersion),
+        }
+    }
+
+    /// Create a proposal that invokes an arbitrary contract function
+    ///
+    /// The target must trust this contract (e.g. hold it as admin), since the
+    /// call is made with this contract's own authorization.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_action(
+        env: &Env,
+        proposer: Address,
+        call: ContractCall,
+        metadata: ProposalMetadata,
+        approval_threshold: u32,
+        approvers: Vec<Address>,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        Self::require_role(env, &proposer, GovernanceRole::Admin)?;
+
+        ValidationModule::validate_metadata(&metadata)?;
+        ValidationModule::validate_action_params(
+            env,
+            &call,
+            approval_threshold,
+            &approvers,
+            timelock_delay,
         )?;
 
+        Self::create_proposal(
+            env,
+            proposer,
+            BytesN::from_array(env, &[0u8; 32]), // No code change
+            call.target.clone(),
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+            ProposalAction::Call(call),
+            0, // No code version involved
+            ProposalParams::new(0),
+        )
+    }
+
+    /// Create a proposal that runs several contract calls atomically
+    ///
+    /// The calls execute in order after one approval round and timelock. The
+    /// proposal's `target_contract` is the first call's target.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_batch(
+        env: &Env,
+        proposer: Address,
+        calls: Vec<ContractCall>,
+        metadata: ProposalMetadata,
+        approval_threshold: u32,
+        approvers: Vec<Address>,
+        timelock_delay: u64,
+    ) -> Result<u64, GovernanceError> {
+        Self::require_role(env, &proposer, GovernanceRole::Admin)?;
+
+        ValidationModule::validate_metadata(&metadata)?;
+        ValidationModule::validate_batch_params(
+            env,
+            &calls,
+            approval_threshold,
+            &approvers,
+            timelock_delay,
+        )?;
+
+        let target_contract = calls.get_unchecked(0).target;
+        Self::create_proposal(
+            env,
+            proposer,
+            BytesN::from_array(env, &[0u8; 32]), // No code change
+            target_contract,
+            metadata,
+            approval_threshold,
+            approvers,
+            timelock_delay,
+            ProposalAction::Batch(calls),
+            0, // No code version involved
+            ProposalParams::new(0),
+        )
+    }
+
+    /// Store a validated proposal and emit its creation event
+    #[allow(clippy::too_many_arguments)]
+    fn create_proposal(
+        env: &Env,
+        proposer: Address,
+        new_contract_hash: BytesN<32>,
+        target_contract: Address,
+        metadata: ProposalMetadata,
+        approval_threshold: u32,
+        approvers: Vec<Address>,
+        timelock_delay: u64,
+        action: ProposalAction,
+        current_version: u32,
+        params: ProposalParams,
+    ) -> Result<u64, GovernanceError> {
+        // By default just enough rejections to make the approval threshold unreachable
+        let rejection_threshold = if params.rejection_threshold == 0 {
+            approvers.len() - approval_threshold + 1
+        } else {
+            params.rejection_threshold
+        };
+
         // Get next proposal ID
         let proposal_counter_key = symbol_short!("prop_cnt");
         let proposal_id: u64 = env
@@ -594,43 +1889,45 @@ impl GovernanceManager {
         let event_proposer = proposer.clone();
         let event_new_contract_hash = new_contract_hash.clone();
         let event_target_contract = target_contract.clone();
-        let event_description = description.clone();
+        let event_title = metadata.title.clone();
+        let event_content_hash = metadata.content_hash.clone();
 
         let proposal = UpgradeProposal {
             id: next_id,
             proposer,
             new_contract_hash,
             target_contract,
-            description,
+            title: metadata.title,
+            content_hash: metadata.content_hash,
             approval_threshold,
             approvers,
             approvals_count: 0,
+            rejection_threshold,
+            rejections_count: 0,
             status: ProposalStatus::Pending,
             created_at: env.ledger().timestamp(),
             execution_time: env.ledger().timestamp() + timelock_delay,
             executed: false,
 
-            // New fields with default values
-            cooling_off_period: 3600, // 1 hour default cooling-off
-            current_version: 1,       // Default to version 1
-            proposed_version: 2,      // Default to version 2
-            simulation_passed: true,  // Default to passed
-            simulation_metadata: symbol_short!("none"), // No simulation by default
-            breaking_change: false,   // Default to non-breaking
+            // Upgrade metadata from the proposer
+            cooling_off_period: params.cooling_off_period,
+            current_version,
+            proposed_version: params.proposed_version,
+            simulation_passed: params.simulation_passed,
+            simulation_metadata: params.simulation_metadata,
+            breaking_change: params.breaking_change,
             halt_reason: symbol_short!(""), // Empty halt reason
-            halted_at: 0,             // Not halted
+            halted_at: 0,                   // Not halted
+
+            action,
+            voting_mode: VotingMode::Multisig,
+            expires_at: env.ledger().timestamp() + PROPOSAL_LIFETIME,
+            action_results: Vec::new(env),
         };
 
         // Store proposal
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .unwrap_or_else(|| soroban_sdk::Map::new(env));
-
-        proposals.set(next_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
+        StorageModule::index_by_target(env, &proposal);
 
         // Update counter
         env.storage()
@@ -645,7 +1942,8 @@ impl GovernanceManager {
                 proposer: event_proposer,
                 new_contract_hash: event_new_contract_hash,
                 target_contract: event_target_contract,
-                description: event_description,
+                title: event_title,
+                content_hash: event_content_hash,
                 approval_threshold,
                 timelock_delay,
                 timestamp: env.ledger().timestamp(),
@@ -660,9 +1958,10 @@ impl GovernanceManager {
         env: &Env,
         proposal_id: u64,
         approver: Address,
+        rationale: Rationale,
     ) -> Result<(), GovernanceError> {
         // Use the enhanced approval module
-        ApprovalModule::approve_with_cooling_off(env, proposal_id, approver)
+        ApprovalModule::approve_with_cooling_off(env, proposal_id, approver, rationale)
     }
 
     /// Execute an approved proposal (only after timelock expires)
@@ -672,18 +1971,9 @@ impl GovernanceManager {
         executor: Address,
     ) -> Result<(), GovernanceError> {
         // Validate executor has permission
-        Self::require_role(env, &executor, GovernanceRole::Executor);
-
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        Self::require_role(env, &executor, GovernanceRole::Executor)?;
 
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
         // Check if proposal is halted
         if proposal.status == ProposalStatus::Halted {
@@ -700,14 +1990,39 @@ impl GovernanceManager {
             return Err(GovernanceError::TimelockNotExpired);
         }
 
+        // An approval that sat unused past its window must be proposed again
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(GovernanceError::ProposalExpired);
+        }
+
+        // Breaking changes only run once their simulation has passed
+        if proposal.breaking_change && !proposal.simulation_passed {
+            return Err(GovernanceError::SimulationNotPassed);
+        }
+
+        // Run the action before recording the execution. A failed call
+        // returns an error, which reverts every call made before it.
+        match proposal.action.clone() {
+            ProposalAction::Upgrade => Self::apply_upgrade(env, &proposal)?,
+            ProposalAction::Call(call) => {
+                let result = Self::apply_call(env, proposal_id, &executor, &call)?;
+                proposal.action_results.push_back(result);
+            }
+            ProposalAction::Batch(calls) => {
+                for call in calls.iter() {
+                    let result = Self::apply_call(env, proposal_id, &executor, &call)?;
+                    proposal.action_results.push_back(result);
+                }
+            }
+        }
+
         // Mark as executed
         proposal.executed = true;
         proposal.status = ProposalStatus::Executed;
 
         let new_contract_hash = proposal.new_contract_hash.clone();
 
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit proposal executed event
         EventEmitter::proposal_executed(
@@ -723,44 +2038,79 @@ impl GovernanceManager {
         Ok(())
     }
 
-    /// Reject a proposal
-    pub fn reject_proposal(
+    /// Invoke one proposal call and return its result
+    fn apply_call(
         env: &Env,
         proposal_id: u64,
-        rejector: Address,
-    ) -> Result<(), GovernanceError> {
-        Self::require_role(env, &rejector, GovernanceRole::Approver);
-
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
-
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
-
-        if proposal.status != ProposalStatus::Pending {
-            return Err(GovernanceError::InvalidProposal);
-        }
+        executor: &Address,
+        call: &ContractCall,
+    ) -> Result<Val, GovernanceError> {
+        let result = safe_invoke(env, &call.target, &call.function, call.args.clone())
+            .map_err(|_| GovernanceError::ActionFailed)?;
 
-        proposal.status = ProposalStatus::Rejected;
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
-
-        // Emit proposal rejected event
-        EventEmitter::proposal_rejected(
+        EventEmitter::action_executed(
             env,
-            ProposalRejectedEvent {
+            ActionExecutedEvent {
                 proposal_id,
-                rejector,
+                executor: executor.clone(),
+                target_contract: call.target.clone(),
+                function: call.function.clone(),
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(())
+        Ok(result)
+    }
+
+    /// Install the proposal's WASM on its target contract
+    ///
+    /// A contract governing itself swaps its own code; any other target must
+    /// expose an `upgrade(new_wasm_hash, new_version)` entrypoint that trusts
+    /// this contract as its admin and records the new version.
+    fn apply_upgrade(env: &Env, proposal: &UpgradeProposal) -> Result<(), GovernanceError> {
+        if proposal.target_contract == env.current_contract_address() {
+            env.deployer()
+                .update_current_contract_wasm(proposal.new_contract_hash.clone());
+            if proposal.proposed_version > 0 {
+                env.storage()
+                    .persistent()
+                    .set(&VERSION_KEY, &proposal.proposed_version);
+            }
+            return Ok(());
+        }
+
+        let mut args: Vec<Val> = Vec::new(env);
+        args.push_back(proposal.new_contract_hash.clone().into_val(env));
+        args.push_back(proposal.proposed_version.into_val(env));
+
+        match env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &proposal.target_contract,
+            &symbol_short!("upgrade"),
+            args,
+        ) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(GovernanceError::UpgradeFailed),
+        }
+    }
+
+    /// Reject a proposal (wrapper for ApprovalModule)
+    pub fn reject_proposal(
+        env: &Env,
+        proposal_id: u64,
+        rejector: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        ApprovalModule::reject(env, proposal_id, rejector, rationale)
+    }
+
+    /// Revoke a rejection (wrapper for ApprovalModule)
+    pub fn revoke_rejection(
+        env: &Env,
+        proposal_id: u64,
+        approver: Address,
+        rationale: Rationale,
+    ) -> Result<(), GovernanceError> {
+        ApprovalModule::revoke_rejection(env, proposal_id, approver, rationale)
     }
 
     /// Cancel a proposal (admin only)
@@ -769,26 +2119,16 @@ impl GovernanceManager {
         proposal_id: u64,
         admin: Address,
     ) -> Result<(), GovernanceError> {
-        Self::require_role(env, &admin, GovernanceRole::Admin);
+        Self::require_role(env, &admin, GovernanceRole::Admin)?;
 
-        let proposals_key = symbol_short!("props");
-        let mut proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
-            .storage()
-            .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
 
-        let mut proposal = proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?;
-
-        if proposal.executed {
+        if proposal.executed || proposal.status == ProposalStatus::Vetoed {
             return Err(GovernanceError::InvalidProposal);
         }
 
         proposal.status = ProposalStatus::Cancelled;
-        proposals.set(proposal_id, proposal);
-        env.storage().persistent().set(&proposals_key, &proposals);
+        StorageModule::save_proposal(env, &proposal);
 
         // Emit proposal cancelled event
         EventEmitter::proposal_cancelled(
@@ -803,18 +2143,104 @@ impl GovernanceManager {
         Ok(())
     }
 
+    /// Mark a pending, approved or halted proposal as expired (callable by anyone)
+    pub fn expire_proposal(env: &Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        let mut proposal = StorageModule::load_proposal(env, proposal_id)?;
+
+        let previous_status = proposal.status;
+        if !matches!(
+            previous_status,
+            ProposalStatus::Pending | ProposalStatus::Approved | ProposalStatus::Halted
+        ) {
+            return Err(GovernanceError::InvalidProposal);
+        }
+        if env.ledger().timestamp() < proposal.expires_at {
+            return Err(GovernanceError::NotExpired);
+        }
+
+        proposal.status = ProposalStatus::Expired;
+        StorageModule::save_proposal(env, &proposal);
+
+        EventEmitter::proposal_expired(
+            env,
+            ProposalExpiredEvent {
+                proposal_id,
+                previous_status,
+                expires_at: proposal.expires_at,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
     /// Get a proposal by ID
     pub fn get_proposal(env: &Env, proposal_id: u64) -> Result<UpgradeProposal, GovernanceError> {
-        let proposals_key = symbol_short!("props");
-        let proposals: soroban_sdk::Map<u64, UpgradeProposal> = env
+        StorageModule::load_proposal(env, proposal_id)
+    }
+
+    /// List proposals with ID >= `start`, optionally only those in `status_filter`
+    ///
+    /// Each call examines at most `limit` (capped at `MAX_PROPOSAL_PAGE`)
+    /// proposals, so a filtered page can hold fewer matches or none; keep
+    /// passing `next` as `start` until it is `None`. A `limit` of 0 returns
+    /// an empty page with no `next`.
+    pub fn list_proposals(
+        env: &Env,
+        start: u64,
+        limit: u32,
+        status_filter: Option<ProposalStatus>,
+    ) -> ProposalPage {
+        let mut proposals = Vec::new(env);
+        let last_id: u64 = env
             .storage()
             .persistent()
-            .get(&proposals_key)
-            .ok_or(GovernanceError::ProposalNotFound)?;
+            .get(&symbol_short!("prop_cnt"))
+            .unwrap_or(0);
+
+        let first = start.max(1);
+        let end = last_id.min(first.saturating_add(limit.min(MAX_PROPOSAL_PAGE) as u64) - 1);
+        for id in first..=end {
+            if let Ok(proposal) = StorageModule::load_proposal(env, id) {
+                if status_filter.is_none_or(|status| proposal.status == status) {
+                    proposals.push_back(ProposalSummary::from(&proposal));
+                }
+            }
+        }
 
-        proposals
-            .get(proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)
+        ProposalPage {
+            proposals,
+            next: (limit > 0 && end < last_id).then_some(end + 1),
+        }
+    }
+
+    /// List proposals targeting `target_contract`, oldest first
+    ///
+    /// `start` is a position in the target's list, not a proposal ID; at most
+    /// `limit` (capped at `MAX_PROPOSAL_PAGE`) are returned per call, and a
+    /// `limit` of 0 returns an empty page with no `next`.
+    pub fn list_proposals_by_target(
+        env: &Env,
+        target_contract: &Address,
+        start: u64,
+        limit: u32,
+    ) -> ProposalPage {
+        let mut proposals = Vec::new(env);
+        let count = StorageModule::target_proposal_count(env, target_contract) as u64;
+
+        let end = count.min(start.saturating_add(limit.min(MAX_PROPOSAL_PAGE) as u64));
+        for index in start..end {
+            if let Some(id) = StorageModule::target_proposal(env, target_contract, index as u32) {
+                if let Ok(proposal) = StorageModule::load_proposal(env, id) {
+                    proposals.push_back(ProposalSummary::from(&proposal));
+                }
+            }
+        }
+
+        ProposalPage {
+            proposals,
+            next: (limit > 0 && end < count).then_some(end),
+        }
     }
 
     /// Halt a proposal (wrapper for HaltModule)
@@ -837,17 +2263,136 @@ impl GovernanceManager {
         HaltModule::resume_proposal(env, proposal_id, admin, new_timelock_delay)
     }
 
+    /// Veto a proposal (wrapper for GuardianModule)
+    pub fn veto_proposal(
+        env: &Env,
+        proposal_id: u64,
+        guardian: Address,
+        reason: Symbol,
+    ) -> Result<(), GovernanceError> {
+        GuardianModule::veto_proposal(env, proposal_id, guardian, reason)
+    }
+
+    /// Register an approver's signing key (wrapper for SignatureModule)
+    pub fn set_signing_key(
+        env: &Env,
+        approver: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), GovernanceError> {
+        SignatureModule::set_signing_key(env, approver, public_key)
+    }
+
+    /// Get the digest an approver signs (wrapper for SignatureModule)
+    pub fn approval_digest(
+        env: &Env,
+        proposal_id: u64,
+        approver: &Address,
+        rationale: &Rationale,
+    ) -> BytesN<32> {
+        SignatureModule::approval_digest(env, proposal_id, approver, rationale)
+    }
+
+    /// Get an approver's signature nonce (wrapper for SignatureModule)
+    pub fn get_signature_nonce(env: &Env, approver: &Address) -> u64 {
+        SignatureModule::get_nonce(env, approver)
+    }
+
+    /// Apply off-chain approvals (wrapper for SignatureModule)
+    pub fn approve_with_signatures(
+        env: &Env,
+        proposal_id: u64,
+        signatures: Vec<ApprovalSignature>,
+    ) -> Result<(), GovernanceError> {
+        SignatureModule::approve_with_signatures(env, proposal_id, signatures)
+    }
+
     /// Revoke an approval (wrapper for ApprovalModule)
     pub fn revoke_approval(
         env: &Env,
         proposal_id: u64,
         approver: Address,
+        rationale: Rationale,
     ) -> Result<(), GovernanceError> {
-        ApprovalModule::revoke_approval(env, proposal_id, approver)
+        ApprovalModule::revoke_approval(env, proposal_id, approver, rationale)
     }
 
     /// Get time to execution (wrapper for ApprovalModule)
     pub fn get_time_to_execution(env: &Env, proposal_id: u64) -> Result<u64, GovernanceError> {
         ApprovalModule::get_time_to_execution(env, proposal_id)
     }
+
+    /// Grant a role (wrapper for RoleModule)
+    pub fn grant_role(
+        env: &Env,
+        admin: Address,
+        member: Address,
+        role: GovernanceRole,
+    ) -> Result<(), GovernanceError> {
+        RoleModule::grant_role(env, admin, member, role)
+    }
+
+    /// Revoke a role (wrapper for RoleModule)
+    pub fn revoke_role(env: &Env, admin: Address, member: Address) -> Result<(), GovernanceError> {
+        RoleModule::revoke_role(env, admin, member)
+    }
+
+    /// Get a member's role (wrapper for RoleModule)
+    pub fn get_role(env: &Env, member: &Address) -> Option<GovernanceRole> {
+        RoleModule::get_role(env, member)
+    }
+
+    /// List members holding a role (wrapper for RoleModule)
+    pub fn list_members(env: &Env, role: GovernanceRole) -> Vec<Address> {
+        RoleModule::list_members(env, role)
+    }
+
+    /// Decide a proposal by token vote (wrapper for VotingModule)
+    pub fn enable_token_voting(
+        env: &Env,
+        proposal_id: u64,
+        admin: Address,
+        token: Address,
+        voting_period: u64,
+        quorum_bps: u32,
+        majority_bps: u32,
+    ) -> Result<(), GovernanceError> {
+        VotingModule::enable_token_voting(
+            env,
+            proposal_id,
+            admin,
+            token,
+            voting_period,
+            quorum_bps,
+            majority_bps,
+        )
+    }
+
+    /// Cast a token-weighted vote (wrapper for VotingModule)
+    pub fn cast_vote(
+        env: &Env,
+        proposal_id: u64,
+        voter: Address,
+        choice: VoteChoice,
+    ) -> Result<i128, GovernanceError> {
+        VotingModule::cast_vote(env, proposal_id, voter, choice)
+    }
+
+    /// Close a token vote (wrapper for VotingModule)
+    pub fn finalize_vote(env: &Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        VotingModule::finalize_vote(env, proposal_id)
+    }
+
+    /// Split legacy shared maps into per-proposal keys (wrapper for StorageModule)
+    pub fn migrate_legacy_storage(
+        env: &Env,
+        admin: Address,
+        max_entries: u32,
+    ) -> Result<u32, GovernanceError> {
+        StorageModule::migrate_legacy_storage(env, admin, max_entries)
+    }
+
+    /// Get a token vote tally (wrapper for VotingModule)
+    pub fn get_vote_tally(env: &Env, proposal_id: u64) -> VoteTally {
+        VotingModule::get_tally(env, proposal_id)
+    }
 }
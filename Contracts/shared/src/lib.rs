@@ -10,9 +10,11 @@ pub struct ContractConfig {
     pub is_paused: bool,
 }
 
+pub mod admin_transfer;
 pub mod events;
 pub mod fees;
 pub mod governance;
+pub mod safe_call;
 pub mod state_verification;
 
 /// Standard contract error codes
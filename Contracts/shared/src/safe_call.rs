@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Symbol, Val, Vec, Error};
+use soroban_sdk::{Address, Env, Error, Symbol, Val, Vec};
 
 pub mod errors {
     pub const CALL_FAILED: u32 = 2001;
@@ -6,13 +6,13 @@ pub mod errors {
 }
 
 /// Safely invokes a contract method with error handling checks.
-/// 
+///
 /// # Arguments
 /// * `env` - The environment
 /// * `contract` - The address of the contract to call
 /// * `func` - The function name to call
 /// * `args` - The arguments to pass
-/// 
+///
 /// # Returns
 /// * `Result<Val, u32>` - The return value or an error code
 pub fn safe_invoke(
@@ -21,27 +21,11 @@ pub fn safe_invoke(
     func: &Symbol,
     args: Vec<Val>,
 ) -> Result<Val, u32> {
-    // 1. Defensive Check: Ensure contract exists/is deployed?
-    // Soroban doesn't have a direct "exists" check on Address easily accessible without trying to call 
-    // or checking ledger entries, but try_call handles non-existence as an error.
-
-    // 2. Try Call
-    // invoke_contract_try returns Result<Val, Error>
-    // We map generic errors to our specific codes if needed, or propagate.
-    
-    // Explicitly type the result to see what compiler thinks
-    let res: Result<Val, Error> = env.try_invoke_contract(contract, func, args);
-
-    match res {
-        Ok(val) => Ok(val),
-        Err(e) => {
-            // Log the error for debugging
-            // env.events().publish((Symbol::new(env, "call_failed"),), e);
-            
-            // In a real module we might inspect `e` to see if it's a missing contract vs logic error.
-            // For now, we wrap it.
-            Err(errors::CALL_FAILED)
-        }
+    // try_invoke_contract handles a missing contract as an error, so no
+    // separate existence check is needed.
+    match env.try_invoke_contract::<Val, Error>(contract, func, args) {
+        Ok(Ok(val)) => Ok(val),
+        _ => Err(errors::CALL_FAILED),
     }
 }
 
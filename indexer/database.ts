@@ -72,9 +72,11 @@ export class EventDatabase {
         proposer TEXT NOT NULL,
         new_contract_hash TEXT NOT NULL,
         target_contract TEXT NOT NULL,
-        description TEXT NOT NULL,
+        title TEXT NOT NULL,
+        content_hash TEXT NOT NULL,
         approval_threshold INTEGER NOT NULL,
         current_approvals INTEGER DEFAULT 0,
+        current_rejections INTEGER DEFAULT 0,
         timelock_delay INTEGER NOT NULL,
         status TEXT DEFAULT 'pending',
         created_at INTEGER NOT NULL,
@@ -235,8 +237,8 @@ export class EventDatabase {
     const stmt = this.db.prepare(`
       INSERT OR IGNORE INTO proposals (
         proposal_id, contract_id, proposer, new_contract_hash, target_contract,
-        description, approval_threshold, timelock_delay, status, created_at, ledger, tx_hash
-      ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        title, content_hash, approval_threshold, timelock_delay, status, created_at, ledger, tx_hash
+      ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
     `);
 
     stmt.run(
@@ -245,7 +247,8 @@ export class EventDatabase {
       proposal.proposer,
       proposal.new_contract_hash,
       proposal.target_contract,
-      proposal.description,
+      proposal.title,
+      proposal.content_hash,
       proposal.approval_threshold,
       Number(proposal.timelock_delay),
       proposal.status,
@@ -271,6 +274,14 @@ export class EventDatabase {
     stmt.run(approvals, contractId, proposalId.toString());
   }
 
+  updateProposalRejections(contractId: string, proposalId: bigint, rejections: number): void {
+    const stmt = this.db.prepare(`
+      UPDATE proposals SET current_rejections = ?, updated_at = CURRENT_TIMESTAMP
+      WHERE contract_id = ? AND proposal_id = ?
+    `);
+    stmt.run(rejections, contractId, proposalId.toString());
+  }
+
   getActiveProposals(contractId: string): Proposal[] {
     const stmt = this.db.prepare(`
       SELECT * FROM proposals WHERE contract_id = ? AND status IN ('pending', 'approved')
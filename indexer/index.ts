@@ -25,6 +25,8 @@ import {
   type TradeExecutedEvent,
   type ProposalCreatedEvent,
   type ProposalApprovedEvent,
+  type ProposalRejectedEvent,
+  type Rationale,
   type RewardAddedEvent,
   type RewardClaimedEvent,
   type ContractPausedEvent,
@@ -57,6 +59,10 @@ function decodeEventValue(value: StellarSdk.xdr.ScVal): unknown {
   switch (scValType) {
     case 'scvBool':
       return value.b();
+    case 'scvU32':
+      return value.u32();
+    case 'scvI32':
+      return value.i32();
     case 'scvU64':
       return BigInt(value.u64().toString());
     case 'scvI64':
@@ -95,11 +101,20 @@ function decodeEventValue(value: StellarSdk.xdr.ScVal): unknown {
       if (!vec) return [];
       return vec.map(v => decodeEventValue(v));
     }
+    case 'scvBytes':
+      return value.bytes().toString('hex');
     default:
       return value.toXDR('base64');
   }
 }
 
+/**
+ * Formats a decoded rationale for logging
+ */
+function formatRationale(rationale: Rationale): string {
+  return rationale[0] === 'Hash' ? `, Rationale: ${rationale[1]}` : '';
+}
+
 /**
  * Extracts the topic string from event topics
  */
@@ -153,7 +168,7 @@ class EventHandler {
     ledger: number,
     txHash: string
   ): void {
-    console.log(`[Proposal] ID: ${data.proposal_id}, Proposer: ${data.proposer}`);
+    console.log(`[Proposal] ID: ${data.proposal_id}, Proposer: ${data.proposer}, Title: ${data.title}`);
 
     this.db.insertProposal({
       proposal_id: data.proposal_id,
@@ -161,7 +176,8 @@ class EventHandler {
       proposer: data.proposer,
       new_contract_hash: data.new_contract_hash,
       target_contract: data.target_contract,
-      description: data.description,
+      title: data.title,
+      content_hash: data.content_hash,
       approval_threshold: data.approval_threshold,
       timelock_delay: data.timelock_delay,
       status: 'pending',
@@ -172,7 +188,7 @@ class EventHandler {
   }
 
   handleProposalApproved(contractId: string, data: ProposalApprovedEvent): void {
-    console.log(`[Approval] Proposal ${data.proposal_id} approved by ${data.approver} (${data.current_approvals}/${data.threshold})`);
+    console.log(`[Approval] Proposal ${data.proposal_id} approved by ${data.approver} (${data.current_approvals}/${data.threshold})${formatRationale(data.rationale)}`);
 
     this.db.updateProposalApprovals(contractId, data.proposal_id, data.current_approvals);
 
@@ -181,9 +197,14 @@ class EventHandler {
     }
   }
 
-  handleProposalRejected(contractId: string, proposalId: bigint): void {
-    console.log(`[Reject] Proposal ${proposalId} rejected`);
-    this.db.updateProposalStatus(contractId, proposalId, 'rejected');
+  handleProposalRejected(contractId: string, data: ProposalRejectedEvent): void {
+    console.log(`[Reject] Proposal ${data.proposal_id} rejected by ${data.rejector} (${data.current_rejections}/${data.threshold})${formatRationale(data.rationale)}`);
+
+    this.db.updateProposalRejections(contractId, data.proposal_id, data.current_rejections);
+
+    if (data.current_rejections >= data.threshold) {
+      this.db.updateProposalStatus(contractId, data.proposal_id, 'rejected');
+    }
   }
 
   handleProposalExecuted(contractId: string, proposalId: bigint): void {
@@ -356,7 +377,7 @@ class EventIndexer {
         this.handler.handleProposalApproved(contractId, data as unknown as ProposalApprovedEvent);
         break;
       case EVENT_TOPICS.PROPOSAL_REJECTED:
-        this.handler.handleProposalRejected(contractId, (data as any).proposal_id);
+        this.handler.handleProposalRejected(contractId, data as unknown as ProposalRejectedEvent);
         break;
       case EVENT_TOPICS.PROPOSAL_EXECUTED:
         this.handler.handleProposalExecuted(contractId, (data as any).proposal_id);
@@ -100,12 +100,19 @@ export interface FeeCollectedEvent {
 // Governance Events
 // =============================================================================
 
+/**
+ * Hash of an approver's or rejector's off-chain rationale, decoded from the
+ * contract's `Rationale` enum
+ */
+export type Rationale = ['None'] | ['Hash', string];
+
 export interface ProposalCreatedEvent {
   proposal_id: bigint;
   proposer: string;
   new_contract_hash: string;
   target_contract: string;
-  description: string;
+  title: string;
+  content_hash: string;
   approval_threshold: number;
   timelock_delay: bigint;
   timestamp: bigint;
@@ -116,12 +123,16 @@ export interface ProposalApprovedEvent {
   approver: string;
   current_approvals: number;
   threshold: number;
+  rationale: Rationale;
   timestamp: bigint;
 }
 
 export interface ProposalRejectedEvent {
   proposal_id: bigint;
   rejector: string;
+  current_rejections: number;
+  threshold: number;
+  rationale: Rationale;
   timestamp: bigint;
 }
 
@@ -425,7 +436,8 @@ export interface Proposal {
   proposer: string;
   new_contract_hash: string;
   target_contract: string;
-  description: string;
+  title: string;
+  content_hash: string;
   approval_threshold: number;
   timelock_delay: bigint;
   status: 'pending' | 'approved' | 'rejected' | 'executed' | 'cancelled';